@@ -0,0 +1,53 @@
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    ranks: Vec<usize>,
+}
+
+#[allow(dead_code)]
+impl DisjointSet {
+    /// Returns a new forest of `n` singleton sets, one for each element in 0..n
+    pub fn new(n: usize) -> Self {
+        DisjointSet {
+            parents: (0..n).collect(),
+            ranks: vec![0; n],
+        }
+    }
+
+    /// Returns the representative of the set containing `x`, compressing the path to it.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut i = x;
+        while self.parents[i] != root {
+            let next = self.parents[i];
+            self.parents[i] = root;
+            i = next;
+        }
+        root
+    }
+
+    /// Merges the sets containing `x` and `y` (union by rank).
+    /// Returns false if they were already in the same set.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let x_root = self.find(x);
+        let y_root = self.find(y);
+        if x_root == y_root {
+            return false;
+        }
+        if self.ranks[x_root] < self.ranks[y_root] {
+            self.parents[x_root] = y_root;
+        } else if self.ranks[x_root] > self.ranks[y_root] {
+            self.parents[y_root] = x_root;
+        } else {
+            self.parents[y_root] = x_root;
+            self.ranks[x_root] += 1;
+        }
+        true
+    }
+
+    pub fn same_set(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+}
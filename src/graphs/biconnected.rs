@@ -0,0 +1,133 @@
+use crate::graphs::{DirectedGraphError, Edge, Graph};
+use crate::graphs::GraphType::GraphDirected;
+
+/// Everything a single low-link DFS finds out about an undirected graph
+struct LowLink<'a, T> where T: Edge {
+    articulation: Vec<bool>,
+    bridges: Vec<&'a T>,
+    components: Vec<Vec<&'a T>>,
+}
+
+impl<T> Graph<T> where T: Edge {
+
+    /// Returns the articulation points (cut vertices) of the undirected graph: the nodes whose
+    /// removal increases the number of connected components, in increasing order
+    /// ( O(|E|+|V|) with |V| = number of nodes, |E| = number of edges).
+    #[allow(dead_code)]
+    pub fn articulation_points(&self) -> Result<Vec<usize>, DirectedGraphError> {
+        let low_link = self.low_link()?;
+        Ok((0..self.n_nodes).filter(|v| low_link.articulation[*v]).collect())
+    }
+
+    /// Returns the bridges of the undirected graph: the edges whose removal increases the
+    /// number of connected components ( O(|E|+|V|) with |V| = number of nodes,
+    /// |E| = number of edges). Each bridge is returned once, in one of its two directions.
+    #[allow(dead_code)]
+    pub fn bridges(&self) -> Result<Vec<&T>, DirectedGraphError> {
+        Ok(self.low_link()?.bridges)
+    }
+
+    /// Returns the biconnected components of the undirected graph as a partition of its
+    /// edges ( O(|E|+|V|) with |V| = number of nodes, |E| = number of edges): two edges are
+    /// in the same component when they lie on a common simple cycle.
+    ///
+    /// Each edge is returned once, in one of its two directions. A bridge is a component on
+    /// its own, and so is each self loop.
+    #[allow(dead_code)]
+    pub fn biconnected_components(&self) -> Result<Vec<Vec<&T>>, DirectedGraphError> {
+        let mut components = self.low_link()?.components;
+        components.extend(self.unique_edges()
+            .into_iter()
+            .filter(|edge| edge.source() == edge.destination())
+            .map(|edge| vec![edge]));
+        Ok(components)
+    }
+
+    /// Runs the DFS computing the discover time of each node and the lowest discover time
+    /// reachable from its subtree with at most one back edge (the low-link).
+    ///
+    /// Only one copy of the edge leading to a node from its parent is skipped, so parallel
+    /// edges are back edges as any other. Self loops are ignored.
+    fn low_link(&self) -> Result<LowLink<'_, T>, DirectedGraphError> {
+        if self.g_type == GraphDirected {
+            return Err(DirectedGraphError);
+        }
+        // 0 means not discovered yet
+        let mut discover: Vec<usize> = vec![0; self.n_nodes];
+        let mut low: Vec<usize> = vec![0; self.n_nodes];
+        let mut time = 0;
+        let mut result = LowLink { articulation: vec![false; self.n_nodes], bridges: Vec::new(), components: Vec::new() };
+        let mut edge_stack: Vec<&T> = Vec::new();
+
+        for root in 0..self.n_nodes {
+            if discover[root] != 0 {
+                continue;
+            }
+            time += 1;
+            discover[root] = time;
+            low[root] = time;
+            let mut root_children = 0;
+            // (node, edge from its parent, index of the next edge to follow, parent edge skipped)
+            let mut stack: Vec<(usize, Option<&T>, usize, bool)> = vec![(root, None, 0, false)];
+
+            while let Some(top) = stack.last_mut() {
+                let (v, parent, next, skipped) = *top;
+                match self.edges[v].get(next) {
+                    Some(edge) => {
+                        top.2 += 1;
+                        let w = edge.destination();
+                        if w == v {
+                            continue;
+                        }
+                        if !skipped && parent.is_some_and(|parent| parent.source() == w) {
+                            top.3 = true;
+                            continue;
+                        }
+                        if discover[w] == 0 {
+                            time += 1;
+                            discover[w] = time;
+                            low[w] = time;
+                            if v == root {
+                                root_children += 1;
+                            }
+                            edge_stack.push(edge);
+                            stack.push((w, Some(edge), 0, false));
+                        } else if discover[w] < discover[v] {
+                            // back edge, the other copy is met from w and ignored
+                            edge_stack.push(edge);
+                            low[v] = low[v].min(discover[w]);
+                        }
+                    }
+                    None => {
+                        stack.pop();
+                        if let Some(edge) = parent {
+                            let u = edge.source();
+                            low[u] = low[u].min(low[v]);
+                            if low[v] >= discover[u] {
+                                if u != root {
+                                    result.articulation[u] = true;
+                                }
+                                let mut component: Vec<&T> = Vec::new();
+                                while let Some(last) = edge_stack.pop() {
+                                    component.push(last);
+                                    if std::ptr::eq(last, edge) {
+                                        break;
+                                    }
+                                }
+                                result.components.push(component);
+                            }
+                            if low[v] > discover[u] {
+                                result.bridges.push(edge);
+                            }
+                        }
+                    }
+                }
+            }
+            if root_children > 1 {
+                result.articulation[root] = true;
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,163 @@
+use crate::graphs::{DirectedGraphError, Edge, Graph};
+use crate::graphs::GraphType::GraphDirected;
+
+impl<T> Graph<T> where T: Edge {
+
+    /// Returns all the maximal cliques of the undirected graph, each one as a sorted node set
+    /// and all of them in lexicographic order, with the Bron-Kerbosch Algorithm
+    /// ( O(d|V|3^(d/3)) with |V| = number of nodes and d = degeneracy of the graph).
+    ///
+    /// The nodes are taken in degeneracy order at the outer level, so each search only runs
+    /// on the at most d neighbours following the node, and every recursive call skips the
+    /// neighbours of a pivot chosen to leave the fewest candidates. Self loops are ignored.
+    #[allow(dead_code)]
+    pub fn maximal_cliques(&self) -> Result<Vec<Vec<usize>>, DirectedGraphError> {
+        Ok(maximal_cliques(&self.simple_neighbors()?, false))
+    }
+
+    /// Returns a clique of the undirected graph with the most nodes, as a sorted node set,
+    /// with the search of `maximal_cliques` skipping the branches that can't give a clique
+    /// larger than the best one found so far.
+    #[allow(dead_code)]
+    pub fn maximum_clique(&self) -> Result<Vec<usize>, DirectedGraphError> {
+        Ok(largest(maximal_cliques(&self.simple_neighbors()?, true)))
+    }
+
+    /// Returns a set of pairwise non adjacent nodes of the undirected graph with the most
+    /// nodes, as a sorted node set: the complement of the minimum vertex cover found by the
+    /// branch and bound of `min_vertex_cover`, exponential in the size of the cover.
+    ///
+    /// Searching the maximum clique of the complement graph instead would need its O(|V|^2)
+    /// edges. A node with a self loop is adjacent to itself, so it's never in the set.
+    #[allow(dead_code)]
+    pub fn maximum_independent_set(&self) -> Result<Vec<usize>, DirectedGraphError> {
+        if self.g_type == GraphDirected {
+            return Err(DirectedGraphError);
+        }
+        let cover = self.min_vertex_cover();
+        Ok((0..self.n_nodes).filter(|v| cover.binary_search(v).is_err()).collect())
+    }
+
+    /// Returns the sorted neighbours of each node of the undirected graph, without self loops
+    /// and repeated edges
+    fn simple_neighbors(&self) -> Result<Vec<Vec<usize>>, DirectedGraphError> {
+        if self.g_type == GraphDirected {
+            return Err(DirectedGraphError);
+        }
+        Ok(self.edges.iter()
+            .enumerate()
+            .map(|(v, adjacent)| {
+                let mut neighbors: Vec<usize> = adjacent.iter()
+                    .map(|edge| edge.destination())
+                    .filter(|w| *w != v)
+                    .collect();
+                neighbors.sort();
+                neighbors.dedup();
+                neighbors
+            })
+            .collect())
+    }
+}
+
+/// Returns the maximal cliques of the graph with the given sorted `neighbors`, for
+/// `Graph::maximal_cliques`, or only the ones larger than all those found before them if
+/// `growing` is set.
+fn maximal_cliques(neighbors: &[Vec<usize>], growing: bool) -> Vec<Vec<usize>> {
+    let order = degeneracy_order(neighbors);
+    let mut position: Vec<usize> = vec![0; neighbors.len()];
+    for (i, v) in order.iter().enumerate() {
+        position[*v] = i;
+    }
+
+    let mut cliques: Vec<Vec<usize>> = Vec::new();
+    let mut clique: Vec<usize> = Vec::new();
+    for v in order {
+        let (later, earlier): (Vec<usize>, Vec<usize>) = neighbors[v].iter().partition(|w| position[**w] > position[v]);
+        clique.push(v);
+        bron_kerbosch(neighbors, &mut clique, later, earlier, &mut cliques, growing);
+        clique.pop();
+    }
+    cliques.sort();
+    cliques
+}
+
+/// Extends `clique` in every possible way with the `candidates`, reporting the maximal cliques
+/// that contain none of the `excluded` nodes (already tried by a previous branch).
+///
+/// If `growing` is set, the branches whose clique can't get larger than the last reported one
+/// are skipped, so each reported clique is larger than the previous ones.
+fn bron_kerbosch(neighbors: &[Vec<usize>], clique: &mut Vec<usize>, mut candidates: Vec<usize>
+                 , mut excluded: Vec<usize>, cliques: &mut Vec<Vec<usize>>, growing: bool) {
+    let beaten = |clique: &Vec<usize>, candidates: &Vec<usize>, cliques: &Vec<Vec<usize>>| {
+        growing && clique.len() + candidates.len() <= cliques.last().map_or(0, Vec::len)
+    };
+    if beaten(clique, &candidates, cliques) {
+        return;
+    }
+    let adjacent = |v: usize, w: &usize| neighbors[v].binary_search(w).is_ok();
+    let Some(pivot) = candidates.iter()
+        .chain(excluded.iter())
+        .copied()
+        .max_by_key(|u| candidates.iter().filter(|w| adjacent(*u, w)).count()) else {
+        let mut maximal = clique.clone();
+        maximal.sort();
+        cliques.push(maximal);
+        return;
+    };
+
+    let branches: Vec<usize> = candidates.iter().copied().filter(|w| !adjacent(pivot, w)).collect();
+    for v in branches {
+        if beaten(clique, &candidates, cliques) {
+            return;
+        }
+        clique.push(v);
+        bron_kerbosch(neighbors,
+                      clique,
+                      candidates.iter().copied().filter(|w| adjacent(v, w)).collect(),
+                      excluded.iter().copied().filter(|w| adjacent(v, w)).collect(),
+                      cliques,
+                      growing);
+        clique.pop();
+        candidates.retain(|w| *w != v);
+        excluded.push(v);
+    }
+}
+
+/// Returns the nodes ordered removing each time one with the fewest neighbours left
+/// ( O(|E|+|V|) with |V| = number of nodes, |E| = number of edges).
+fn degeneracy_order(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let n = neighbors.len();
+    let mut degree: Vec<usize> = neighbors.iter().map(Vec::len).collect();
+    // the nodes by degree, the ones whose degree has changed since they were added are skipped
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for v in 0..n {
+        buckets[degree[v]].push(v);
+    }
+    let mut removed = vec![false; n];
+    let mut order: Vec<usize> = Vec::with_capacity(n);
+    let mut d = 0;
+    while order.len() < n {
+        match buckets[d].pop() {
+            Some(v) if !removed[v] && degree[v] == d => {
+                removed[v] = true;
+                order.push(v);
+                for &w in neighbors[v].as_slice() {
+                    if !removed[w] {
+                        degree[w] -= 1;
+                        buckets[degree[w]].push(w);
+                    }
+                }
+                // removing a node lowers the degree of the others by one at most
+                d = d.saturating_sub(1);
+            }
+            Some(_) => {}
+            None => d += 1,
+        }
+    }
+    order
+}
+
+/// Returns the first of the largest `sets`, the empty set if there are none
+fn largest(sets: Vec<Vec<usize>>) -> Vec<usize> {
+    sets.into_iter().fold(Vec::new(), |best, set| if set.len() > best.len() { set } else { best })
+}
@@ -0,0 +1,132 @@
+use crate::graphs::{Edge, Graph, Weight};
+
+impl<T> Graph<T> where T: Edge {
+
+    /// Returns true if every edge of the graph has at least one end in `nodes`
+    #[allow(dead_code)]
+    pub fn is_vertex_cover(&self, nodes: &[usize]) -> bool {
+        let in_cover = self.node_set(nodes);
+        self.edges.iter().flatten().all(|edge| in_cover[edge.source()] || in_cover[edge.destination()])
+    }
+
+    /// Returns a vertex cover of the graph at most twice as large as the minimum one
+    /// ( O(|E|+|V|) with |V| = number of nodes, |E| = number of edges), as a sorted node set.
+    ///
+    /// Both ends of every edge whose ends are still uncovered are added: the chosen edges
+    /// share no node, so any cover needs a different node for each of them, while the result
+    /// holds at most two nodes for each of them.
+    /// The direction of the edges is ignored.
+    #[allow(dead_code)]
+    pub fn approx_vertex_cover(&self) -> Vec<usize> {
+        let mut in_cover = vec![false; self.n_nodes];
+        for (u, v) in self.cover_edges() {
+            if !in_cover[u] && !in_cover[v] {
+                in_cover[u] = true;
+                in_cover[v] = true;
+            }
+        }
+        (0..self.n_nodes).filter(|v| in_cover[*v]).collect()
+    }
+
+    /// Returns a vertex cover whose total weight is at most twice the minimum one with the
+    /// pricing method ( O(|E|+|V|) with |V| = number of nodes, |E| = number of edges),
+    /// as a sorted node set.
+    ///
+    /// Each edge pays as much as both its ends can still be paid, the nodes paid their whole
+    /// weight (the tight ones) form the cover. The direction of the edges is ignored.
+    ///
+    /// # Arguments
+    /// * `weights` - non negative weight of each node
+    #[allow(dead_code)]
+    pub fn weighted_vertex_cover<W>(&self, weights: &[W]) -> Vec<usize> where W: Weight {
+        assert_eq!(weights.len(), self.n_nodes, "There must be a weight for each node");
+        let zero = W::zero();
+        let mut unpaid: Vec<W> = weights.to_vec();
+        for (u, v) in self.cover_edges() {
+            if unpaid[u] > zero && unpaid[v] > zero {
+                let price = if unpaid[u] < unpaid[v] { unpaid[u] } else { unpaid[v] };
+                unpaid[u] = unpaid[u] - price;
+                if u != v {
+                    unpaid[v] = unpaid[v] - price;
+                }
+            }
+        }
+        (0..self.n_nodes).filter(|v| unpaid[*v] <= zero).collect()
+    }
+
+    /// Returns a vertex cover with at most `k` nodes as a sorted node set, None if there is
+    /// none ( O(2^k|E|) with |E| = number of edges, so only meant for small covers).
+    ///
+    /// The search branches on the two ends of an uncovered edge, and gives up as soon as a
+    /// matching of the uncovered edges is larger than the nodes still available.
+    /// The direction of the edges is ignored.
+    #[allow(dead_code)]
+    pub fn vertex_cover_of_size(&self, k: usize) -> Option<Vec<usize>> {
+        let edges = self.cover_edges();
+        let mut in_cover = vec![false; self.n_nodes];
+        if branch_cover(&edges, &mut in_cover, k) {
+            Some((0..self.n_nodes).filter(|v| in_cover[*v]).collect())
+        } else {
+            None
+        }
+    }
+
+    /// Returns a minimum vertex cover as a sorted node set, trying every size from the lower
+    /// bound given by `approx_vertex_cover` with `vertex_cover_of_size`: exponential in the
+    /// size of the cover, so only meant for small covers.
+    #[allow(dead_code)]
+    pub fn min_vertex_cover(&self) -> Vec<usize> {
+        let approx = self.approx_vertex_cover();
+        let lower_bound = approx.len().div_ceil(2);
+        (lower_bound..approx.len())
+            .find_map(|k| self.vertex_cover_of_size(k))
+            .unwrap_or(approx)
+    }
+
+    /// Returns every edge once as a pair of nodes, ignoring its direction
+    fn cover_edges(&self) -> Vec<(usize, usize)> {
+        self.unique_edges().into_iter().map(|edge| (edge.source(), edge.destination())).collect()
+    }
+
+    fn node_set(&self, nodes: &[usize]) -> Vec<bool> {
+        let mut in_set = vec![false; self.n_nodes];
+        for v in nodes {
+            in_set[*v] = true;
+        }
+        in_set
+    }
+}
+
+/// Looks for at most `k` more nodes covering the `edges` not covered by `in_cover` yet,
+/// adding them to it, for `Graph::vertex_cover_of_size`.
+fn branch_cover(edges: &[(usize, usize)], in_cover: &mut [bool], k: usize) -> bool {
+    // a matching of the uncovered edges: each of its edges needs a different node
+    let mut matched = vec![false; in_cover.len()];
+    let mut matching = 0;
+    let mut first: Option<(usize, usize)> = None;
+    for &(u, v) in edges {
+        if in_cover[u] || in_cover[v] || matched[u] || matched[v] {
+            continue;
+        }
+        matched[u] = true;
+        matched[v] = true;
+        matching += 1;
+        first.get_or_insert((u, v));
+    }
+    let Some((u, v)) = first else {
+        return true;
+    };
+    if matching > k {
+        return false;
+    }
+
+    let ends = if u == v { vec![u] } else { vec![u, v] };
+    for w in ends {
+        in_cover[w] = true;
+        if branch_cover(edges, in_cover, k - 1) {
+            return true;
+        }
+        in_cover[w] = false;
+    }
+    false
+}
@@ -0,0 +1,107 @@
+use std::io::Write;
+use crate::graphs::{Edge, Graph, Path};
+use crate::graphs::GraphType::GraphDirected;
+
+/// Colors given to the nodes of the different components, reused when they run out
+const COMPONENT_COLORS: [&str; 10] = [
+    "lightblue", "lightcoral", "palegreen", "khaki", "plum",
+    "lightsalmon", "paleturquoise", "lightpink", "wheat", "lightgray",
+];
+
+/// Overlays drawn on top of the Graphviz DOT export of a graph.
+///
+/// # Examples
+///
+/// let spt = graph.dijkstra(0)?;
+/// let style = DotStyle::new().highlight_path(&spt.path_to(9).unwrap());
+/// graph.write_dot(&mut file, &style)?;
+#[derive(Default)]
+pub struct DotStyle {
+    /// Edges drawn in red, as (source, destination) pairs
+    highlighted_edges: Vec<(usize, usize)>,
+    /// Nodes drawn with a red border
+    highlighted_nodes: Vec<usize>,
+    /// Component id of each node, used to fill the nodes with a color per component
+    components: Option<Vec<usize>>,
+}
+
+#[allow(dead_code)]
+impl DotStyle {
+    pub fn new() -> Self {
+        DotStyle::default()
+    }
+
+    /// Highlights the nodes and the edges of `path`
+    pub fn highlight_path<T>(mut self, path: &Path<&T>) -> Self where T: Edge {
+        self.highlighted_nodes.extend_from_slice(path.nodes());
+        self.highlight_edges(path.edges())
+    }
+
+    /// Highlights `edges`, for instance the ones of a spanning tree or of a vertex cover
+    pub fn highlight_edges<T>(mut self, edges: &[&T]) -> Self where T: Edge {
+        self.highlighted_edges.extend(edges.iter().map(|edge| (edge.source(), edge.destination())));
+        self
+    }
+
+    /// Highlights `nodes`, for instance the ones of a cover or of a clique
+    pub fn highlight_nodes(mut self, nodes: &[usize]) -> Self {
+        self.highlighted_nodes.extend_from_slice(nodes);
+        self
+    }
+
+    /// Fills each node with the color of its component, `component[v]` being the id of the component of v
+    pub fn color_components(mut self, component: &[usize]) -> Self {
+        self.components = Some(component.to_vec());
+        self
+    }
+
+    fn is_highlighted(&self, src: usize, dst: usize, directed: bool) -> bool {
+        self.highlighted_edges.iter()
+            .any(|(s, d)| (*s == src && *d == dst) || (!directed && *s == dst && *d == src))
+    }
+}
+
+impl<T> Graph<T> where T: Edge {
+
+    /// Writes the graph in the Graphviz DOT language, drawing the overlays of `style`.
+    ///
+    /// Directed graphs become a `digraph`, undirected ones a `graph` where each edge appears once.
+    #[allow(dead_code)]
+    pub fn write_dot<W>(&self, writer: &mut W, style: &DotStyle) -> std::io::Result<()> where W: Write {
+        let directed = self.g_type == GraphDirected;
+        writeln!(writer, "{} G {{", if directed { "digraph" } else { "graph" })?;
+
+        for v in 0..self.n_nodes {
+            let mut attributes: Vec<String> = Vec::new();
+            if let Some(component) = &style.components {
+                let color = COMPONENT_COLORS[component[v] % COMPONENT_COLORS.len()];
+                attributes.push(format!("style=filled, fillcolor={}", color));
+            }
+            if style.highlighted_nodes.contains(&v) {
+                attributes.push(String::from("color=red, penwidth=2"));
+            }
+            if attributes.is_empty() {
+                writeln!(writer, "    {};", v)?;
+            } else {
+                writeln!(writer, "    {} [{}];", v, attributes.join(", "))?;
+            }
+        }
+
+        let connector = if directed { "->" } else { "--" };
+        for edge in self.unique_edges() {
+            let (src, dst) = (edge.source(), edge.destination());
+            let highlight = if style.is_highlighted(src, dst, directed) { ", color=red, penwidth=2" } else { "" };
+            writeln!(writer, "    {} {} {} [label=\"{}\"{}];", src, connector, dst, edge.weight(), highlight)?;
+        }
+
+        writeln!(writer, "}}")
+    }
+
+    /// Returns the graph in the Graphviz DOT language, see `write_dot`.
+    #[allow(dead_code)]
+    pub fn to_dot(&self, style: &DotStyle) -> String {
+        let mut buffer: Vec<u8> = Vec::new();
+        self.write_dot(&mut buffer, style).expect("Writing to a buffer can't fail");
+        String::from_utf8(buffer).expect("The DOT output is valid UTF-8")
+    }
+}
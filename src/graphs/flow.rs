@@ -0,0 +1,246 @@
+use crate::graphs::{AdjacencyGraph, Distance, Edge, Graph, NegativeEdgeError, Weight};
+use crate::graphs::GraphType::GraphDirected;
+
+/// Result of a maximum flow computation
+#[allow(dead_code)]
+pub struct MaxFlow<'a, T> where T: Edge {
+    /// Total flow from the source to the sink, out of range if it can't be represented
+    pub value: Distance<T::Weight>,
+    /// Flow assigned to each edge of the graph
+    pub edge_flows: Vec<(&'a T, T::Weight)>,
+    /// For each node, true if it is on the source side of the minimum cut
+    pub source_side: Vec<bool>,
+}
+
+#[allow(dead_code)]
+impl<'a, T> MaxFlow<'a, T> where T: Edge {
+    /// Returns the minimum cut as the partition (source side, sink side) of the nodes
+    pub fn min_cut(&self) -> (Vec<usize>, Vec<usize>) {
+        (0..self.source_side.len()).partition(|i| self.source_side[*i])
+    }
+
+    /// Returns the edges crossing the minimum cut: their capacities add up to the flow value
+    pub fn cut_edges(&self) -> Vec<&'a T> {
+        self.edge_flows.iter()
+            .map(|(edge, _)| *edge)
+            .filter(|edge| self.source_side[edge.source()] && !self.source_side[edge.destination()])
+            .collect()
+    }
+}
+
+/// Edge of the residual network, remembering the arc it comes from
+#[derive(Clone)]
+struct ResidualEdge<W> where W: Weight {
+    src: usize,
+    dst: usize,
+    weight: W,
+    arc: usize,
+}
+
+impl<W> Edge for ResidualEdge<W> where W: Weight {
+    type Weight = W;
+
+    fn new(src: usize, dst: usize, weight: W) -> Self {
+        ResidualEdge { src, dst, weight, arc: 0 }
+    }
+
+    fn source(&self) -> usize {
+        self.src
+    }
+
+    fn set_source(&mut self, source: usize) {
+        self.src = source
+    }
+
+    fn destination(&self) -> usize {
+        self.dst
+    }
+
+    fn set_destination(&mut self, destination: usize) {
+        self.dst = destination
+    }
+
+    fn weight(&self) -> W {
+        self.weight
+    }
+
+    fn set_weight(&mut self, weight: W) {
+        self.weight = weight
+    }
+}
+
+/// Arcs of the flow network: the arc 2i is the i-th edge of the graph, the arc 2i+1 is its reverse.
+///
+/// The residual capacities are stored instead of the flows, so they never go below zero
+/// and unsigned weights can be used as capacities.
+struct FlowArcs<W> where W: Weight {
+    n_nodes: usize,
+    src: Vec<usize>,
+    dst: Vec<usize>,
+    capacity: Vec<W>,
+    residual: Vec<W>,
+}
+
+impl<W> FlowArcs<W> where W: Weight {
+    fn new<T>(graph: &Graph<T>) -> Result<Self, NegativeEdgeError> where T: Edge<Weight = W> {
+        let zero = W::zero();
+        let mut arcs = FlowArcs { n_nodes: graph.n_nodes, src: Vec::new(), dst: Vec::new(), capacity: Vec::new(), residual: Vec::new() };
+        for edge in graph.edges.iter().flatten() {
+            if edge.weight() < zero {
+                return Err(NegativeEdgeError);
+            }
+            arcs.src.extend([edge.source(), edge.destination()]);
+            arcs.dst.extend([edge.destination(), edge.source()]);
+            arcs.capacity.extend([edge.weight(), zero]);
+            arcs.residual.extend([edge.weight(), zero]);
+        }
+        Ok(arcs)
+    }
+
+    fn residual(&self, arc: usize) -> W {
+        self.residual[arc]
+    }
+
+    /// Moves `amount` of residual capacity from `arc` to its reverse: the two always add up
+    /// to the capacity of the edge, so they stay in range
+    fn push(&mut self, arc: usize, amount: W) {
+        self.residual[arc] = self.residual[arc] - amount;
+        self.residual[arc ^ 1] = self.residual[arc ^ 1] + amount;
+    }
+
+    /// Returns the flow carried by the edge `arc` belongs to, in its direction
+    fn flow(&self, arc: usize) -> W {
+        self.capacity[arc] - self.residual[arc]
+    }
+
+    /// Returns the residual network: an edge for each arc that can still carry flow
+    fn residual_graph(&self) -> Graph<ResidualEdge<W>> {
+        let mut graph = Graph::new(self.n_nodes, GraphDirected);
+        for arc in 0..self.src.len() {
+            let residual = self.residual(arc);
+            if residual > W::zero() {
+                graph.add_edge(ResidualEdge { src: self.src[arc], dst: self.dst[arc], weight: residual, arc });
+            }
+        }
+        graph
+    }
+
+    fn into_max_flow<T>(self, graph: &Graph<T>, source: usize) -> MaxFlow<'_, T> where T: Edge<Weight = W> {
+        let residual = self.residual_graph();
+        let bfst = residual.bfs(source);
+        let edge_flows: Vec<(&T, W)> = graph.edges.iter()
+            .flatten()
+            .enumerate()
+            .map(|(i, edge)| (edge, self.flow(2 * i)))
+            .collect();
+        // the augmenting paths never enter the source, so no flow does
+        let value = Distance::sum((0..self.src.len())
+            .step_by(2)
+            .filter(|arc| self.src[*arc] == source && self.dst[*arc] != source)
+            .map(|arc| self.flow(arc)));
+        MaxFlow { value, edge_flows, source_side: (0..self.n_nodes).map(|v| bfst.is_reachable(v)).collect() }
+    }
+}
+
+impl<T> Graph<T> where T: Edge {
+
+    /// Returns the maximum flow from `source` to `sink` with the Edmonds-Karp Algorithm
+    /// (O(|V||E|^2) with |V| = number of nodes, |E| = number of edges), using the edge
+    /// weights as capacities.
+    ///
+    /// Each augmenting path is the shortest one found by the BFS on the residual network.
+    #[allow(dead_code)]
+    pub fn edmonds_karp(&self, source: usize, sink: usize) -> Result<MaxFlow<'_, T>, NegativeEdgeError> {
+        let mut arcs = FlowArcs::new(self)?;
+
+        loop {
+            let residual = arcs.residual_graph();
+            let bfst = residual.bfs(source);
+            if source == sink || !bfst.is_reachable(sink) {
+                break;
+            }
+            let mut path: Vec<usize> = Vec::new();
+            let mut bottleneck: Option<T::Weight> = None;
+            let mut node = sink;
+            while let Some(edge) = bfst.predecessor(node) {
+                path.push(edge.arc);
+                bottleneck = match bottleneck {
+                    Some(b) if b <= edge.weight() => Some(b),
+                    _ => Some(edge.weight()),
+                };
+                node = edge.source();
+            }
+            let bottleneck = bottleneck.expect("The sink is reachable and differs from the source");
+            for arc in path {
+                arcs.push(arc, bottleneck);
+            }
+        }
+
+        Ok(arcs.into_max_flow(self, source))
+    }
+
+    /// Returns the maximum flow from `source` to `sink` with the Dinic Algorithm
+    /// (O(|V|^2|E|) with |V| = number of nodes, |E| = number of edges), using the edge
+    /// weights as capacities.
+    ///
+    /// Each phase computes the BFS levels of the residual network and saturates it with a
+    /// blocking flow made only of edges going one level deeper.
+    #[allow(dead_code)]
+    pub fn dinic(&self, source: usize, sink: usize) -> Result<MaxFlow<'_, T>, NegativeEdgeError> {
+        let mut arcs = FlowArcs::new(self)?;
+        let mut adjacent: Vec<Vec<usize>> = vec![Vec::new(); self.n_nodes];
+        for arc in 0..arcs.src.len() {
+            adjacent[arcs.src[arc]].push(arc);
+        }
+
+        loop {
+            let residual = arcs.residual_graph();
+            let bfst = residual.bfs(source);
+            if source == sink || !bfst.is_reachable(sink) {
+                break;
+            }
+            let levels: Vec<Option<usize>> = (0..self.n_nodes).map(|v| bfst.distance(v)).collect();
+            let mut next_arc: Vec<usize> = vec![0; self.n_nodes];
+            let mut path: Vec<usize> = Vec::new();
+            let mut node = source;
+            loop {
+                if node == sink {
+                    let bottleneck = path.iter()
+                        .map(|arc| arcs.residual(*arc))
+                        .reduce(|a, b| if b < a { b } else { a })
+                        .expect("The sink differs from the source");
+                    for arc in path.as_slice() {
+                        arcs.push(*arc, bottleneck);
+                    }
+                    path.clear();
+                    node = source;
+                    continue;
+                }
+                let mut advanced = false;
+                while next_arc[node] < adjacent[node].len() {
+                    let arc = adjacent[node][next_arc[node]];
+                    let dst = arcs.dst[arc];
+                    if arcs.residual(arc) > T::Weight::zero() && levels[dst] == levels[node].map(|level| level + 1) {
+                        path.push(arc);
+                        node = dst;
+                        advanced = true;
+                        break;
+                    }
+                    next_arc[node] += 1;
+                }
+                if !advanced {
+                    // dead end: retreat and never try the arc leading here again in this phase
+                    match path.pop() {
+                        Some(arc) => {
+                            node = arcs.src[arc];
+                            next_arc[node] += 1;
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        Ok(arcs.into_max_flow(self, source))
+    }
+}
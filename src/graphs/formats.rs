@@ -0,0 +1,190 @@
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::str::FromStr;
+use sscanf::scanf;
+use crate::graphs::{Edge, Graph, GraphParseError, NormalEdge, Weight};
+use crate::graphs::GraphType::{GraphDirected, GraphUndirected};
+
+/// Returns the graph described by the DIMACS shortest path file (`.gr`) at `path`,
+/// see `dimacs_from_reader` for the format.
+#[allow(dead_code)]
+pub fn dimacs_from_file<W>(path: String) -> Result<Graph<NormalEdge<W>>, GraphParseError> where W: Weight + FromStr {
+    let file = fs::File::open(path)?;
+    dimacs_from_reader(BufReader::new(file))
+}
+
+/// Returns the directed graph read from a DIMACS shortest path (`.gr`) `reader`.
+///
+/// Lines starting with `c` are comments, the problem line `p sp n m` declares the number of
+/// nodes and arcs, and each arc line `a u v w` holds a source, a destination and a weight.
+/// The nodes are numbered from 1 in the file and from 0 in the graph, the weights are parsed as `W`.
+#[allow(dead_code)]
+pub fn dimacs_from_reader<W, R>(reader: R) -> Result<Graph<NormalEdge<W>>, GraphParseError> where W: Weight + FromStr, R: BufRead {
+    let mut header: Option<(usize, Graph<NormalEdge<W>>)> = None;
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line_number = i + 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('c') {
+            continue;
+        }
+        match header.as_mut() {
+            None => {
+                let (n_nodes, n_arcs) = scanf!(trimmed, "p sp {} {}", usize, usize)
+                    .map_err(|_| GraphParseError::BadFormat { line: line_number, text: line.clone(), expected: "p sp nodes arcs" })?;
+                header = Some((n_arcs, Graph::new(n_nodes, GraphDirected)));
+            }
+            Some((_, graph)) => {
+                let (src, dst, weight) = scanf!(trimmed, "a {} {} {}", usize, usize, String).ok()
+                    .and_then(|(src, dst, weight)| Some((src, dst, weight.parse::<W>().ok()?)))
+                    .ok_or_else(|| GraphParseError::BadFormat { line: line_number, text: line.clone(), expected: "a source destination weight" })?;
+                let src = one_based_node(src, graph.n_nodes(), line_number, &line)?;
+                let dst = one_based_node(dst, graph.n_nodes(), line_number, &line)?;
+                graph.create_edge(src, dst, weight);
+            }
+        }
+    }
+
+    let (n_arcs, graph) = header.ok_or(GraphParseError::BadFormat { line: 1, text: String::new(), expected: "p sp nodes arcs" })?;
+    if graph.n_edges != n_arcs {
+        return Err(GraphParseError::EdgeCountMismatch { expected: n_arcs, found: graph.n_edges });
+    }
+    Ok(graph)
+}
+
+/// Returns the graph described by the Matrix Market file at `path`,
+/// see `matrix_market_from_reader` for the format.
+#[allow(dead_code)]
+pub fn matrix_market_from_file<W>(path: String) -> Result<Graph<NormalEdge<W>>, GraphParseError> where W: Weight + FromStr {
+    let file = fs::File::open(path)?;
+    matrix_market_from_reader(BufReader::new(file))
+}
+
+/// Returns the graph read from a Matrix Market `reader`, the adjacency matrix being stored
+/// in coordinate form.
+///
+/// The banner `%%MatrixMarket matrix coordinate <field> <symmetry>` comes first, `field` being
+/// `real`, `integer` or `pattern` (every edge weighs 1) and `symmetry` being `general`
+/// (directed graph) or `symmetric` (undirected graph). The other lines starting with `%` are
+/// comments, then the size line `rows columns entries` and one `row column [value]` line per
+/// entry follow. The nodes are numbered from 1 in the file and from 0 in the graph, the values
+/// are parsed as `W`.
+#[allow(dead_code)]
+pub fn matrix_market_from_reader<W, R>(reader: R) -> Result<Graph<NormalEdge<W>>, GraphParseError> where W: Weight + FromStr, R: BufRead {
+    const BANNER: &str = "%%MatrixMarket matrix coordinate real|integer|pattern general|symmetric";
+    const SIZE: &str = "nodes nodes entries";
+    let mut lines = reader.lines().enumerate();
+
+    let (pattern, symmetric) = match lines.next() {
+        None => return Err(GraphParseError::BadFormat { line: 1, text: String::new(), expected: BANNER }),
+        Some((_, line)) => {
+            let line = line?;
+            let banner: Vec<String> = line.split_whitespace().map(|word| word.to_lowercase()).collect();
+            let banner: Vec<&str> = banner.iter().map(String::as_str).collect();
+            match banner.as_slice() {
+                ["%%matrixmarket", "matrix", "coordinate", field, symmetry]
+                if ["real", "integer", "pattern"].contains(field) && ["general", "symmetric"].contains(symmetry) =>
+                    (*field == "pattern", *symmetry == "symmetric"),
+                _ => return Err(GraphParseError::BadFormat { line: 1, text: line, expected: BANNER }),
+            }
+        }
+    };
+
+    let mut size: Option<(usize, Graph<NormalEdge<W>>)> = None;
+    let mut found = 0;
+    for (i, line) in lines {
+        let line = line?;
+        let line_number = i + 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('%') {
+            continue;
+        }
+        match size.as_mut() {
+            None => {
+                let (rows, columns, entries) = scanf!(trimmed, "{} {} {}", usize, usize, usize)
+                    .map_err(|_| GraphParseError::BadFormat { line: line_number, text: line.clone(), expected: SIZE })?;
+                if rows != columns {
+                    return Err(GraphParseError::BadFormat { line: line_number, text: line.clone(), expected: SIZE });
+                }
+                size = Some((entries, Graph::new(rows, if symmetric { GraphUndirected } else { GraphDirected })));
+            }
+            Some((_, graph)) => {
+                let entry = if pattern {
+                    scanf!(trimmed, "{} {}", usize, usize).ok().map(|(row, column)| (row, column, W::one()))
+                } else {
+                    scanf!(trimmed, "{} {} {}", usize, usize, String).ok()
+                        .and_then(|(row, column, value)| Some((row, column, value.parse::<W>().ok()?)))
+                };
+                let (row, column, weight) = entry.ok_or_else(|| GraphParseError::BadFormat {
+                    line: line_number,
+                    text: line.clone(),
+                    expected: if pattern { "row column" } else { "row column value" },
+                })?;
+                let src = one_based_node(row, graph.n_nodes(), line_number, &line)?;
+                let dst = one_based_node(column, graph.n_nodes(), line_number, &line)?;
+                graph.create_edge(src, dst, weight);
+                found += 1;
+            }
+        }
+    }
+
+    let (entries, graph) = size.ok_or(GraphParseError::BadFormat { line: 2, text: String::new(), expected: SIZE })?;
+    if found != entries {
+        return Err(GraphParseError::EdgeCountMismatch { expected: entries, found });
+    }
+    Ok(graph)
+}
+
+/// Converts the 1-based `node` of a file line into a 0-based node of a graph with `n_nodes` nodes.
+fn one_based_node(node: usize, n_nodes: usize, line: usize, text: &str) -> Result<usize, GraphParseError> {
+    if node == 0 || node > n_nodes {
+        return Err(GraphParseError::NodeOutOfRange { line, text: String::from(text), node, n_nodes });
+    }
+    Ok(node - 1)
+}
+
+impl<T> Graph<T> where T: Edge {
+
+    /// Writes the graph in the DIMACS shortest path (`.gr`) format, numbering the nodes from 1.
+    ///
+    /// The format only knows arcs, so each edge of an undirected graph is written in both directions.
+    #[allow(dead_code)]
+    pub fn write_dimacs<W>(&self, writer: &mut W) -> std::io::Result<()> where W: Write {
+        let mut arcs: Vec<(usize, usize, T::Weight)> = Vec::new();
+        for edge in self.unique_edges() {
+            let (src, dst) = (edge.source(), edge.destination());
+            arcs.push((src, dst, edge.weight()));
+            if self.g_type == GraphUndirected && src != dst {
+                arcs.push((dst, src, edge.weight()));
+            }
+        }
+
+        writeln!(writer, "p sp {} {}", self.n_nodes, arcs.len())?;
+        for (src, dst, weight) in arcs {
+            writeln!(writer, "a {} {} {}", src + 1, dst + 1, weight)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the adjacency matrix of the graph in the Matrix Market coordinate format,
+    /// numbering the nodes from 1.
+    ///
+    /// Undirected graphs are written as `symmetric` matrices, storing only the lower triangle.
+    #[allow(dead_code)]
+    pub fn write_matrix_market<W>(&self, writer: &mut W) -> std::io::Result<()> where W: Write {
+        let edges = self.unique_edges();
+        let symmetry = if self.g_type == GraphDirected { "general" } else { "symmetric" };
+
+        writeln!(writer, "%%MatrixMarket matrix coordinate real {}", symmetry)?;
+        writeln!(writer, "{} {} {}", self.n_nodes, self.n_nodes, edges.len())?;
+        for edge in edges {
+            let (mut row, mut column) = (edge.source(), edge.destination());
+            if self.g_type == GraphUndirected && row < column {
+                std::mem::swap(&mut row, &mut column);
+            }
+            writeln!(writer, "{} {} {}", row + 1, column + 1, edge.weight())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+//! Random graphs, to test and benchmark the algorithms without relying on input files.
+//!
+//! Every generator takes the random number generator to use, so a seeded one
+//! (e.g. `StdRng::seed_from_u64(42)`) always produces the same graph.
+//!
+//! # Examples
+//!
+//! let mut rng = StdRng::seed_from_u64(42);
+//! let graph: Graph<NormalEdge> = gnp(100, 0.1, GraphUndirected, &WeightDistribution::Uniform(1.0, 10.0), &mut rng);
+
+#![allow(dead_code)]
+
+use std::collections::HashSet;
+use rand::distributions::uniform::SampleUniform;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::graphs::{Edge, Graph, GraphType, Weight};
+use crate::graphs::GraphType::{GraphDirected, GraphUndirected};
+
+/// Distribution the weights of the generated edges are drawn from
+#[derive(Debug, Clone, Copy)]
+pub enum WeightDistribution<W = f32> where W: Weight {
+    /// Every edge gets the same weight
+    Constant(W),
+    /// Weights drawn uniformly from `[low, high)`
+    Uniform(W, W),
+    /// Weights drawn uniformly from `[low, high]`, that is any integer between the two
+    /// with an integer weight type
+    Inclusive(W, W),
+}
+
+impl<W> WeightDistribution<W> where W: Weight + SampleUniform {
+    /// Returns a weight drawn from the distribution
+    pub fn sample<R>(&self, rng: &mut R) -> W where R: Rng {
+        match *self {
+            WeightDistribution::Constant(weight) => weight,
+            WeightDistribution::Uniform(low, high) => if low < high { rng.gen_range(low..high) } else { low },
+            WeightDistribution::Inclusive(low, high) => rng.gen_range(low..=high),
+        }
+    }
+}
+
+/// Returns an Erdős–Rényi G(n, p) graph: each of the possible edges between two different
+/// nodes is added with probability `p`.
+pub fn gnp<T, R>(n: usize, p: f64, g_type: GraphType, weights: &WeightDistribution<T::Weight>, rng: &mut R) -> Graph<T>
+    where T: Edge, T::Weight: SampleUniform, R: Rng {
+    let mut graph = Graph::new(n, g_type);
+    let directed = graph.g_type == GraphDirected;
+    for_random_pairs(n, p, directed, rng, |src, dst, rng| graph.create_edge(src, dst, weights.sample(rng)));
+    graph
+}
+
+/// Returns an Erdős–Rényi G(n, m) graph: `m` edges chosen uniformly among the possible
+/// edges between two different nodes.
+///
+/// # Panics
+///
+/// If `m` is greater than the number of possible edges.
+pub fn gnm<T, R>(n: usize, m: usize, g_type: GraphType, weights: &WeightDistribution<T::Weight>, rng: &mut R) -> Graph<T>
+    where T: Edge, T::Weight: SampleUniform, R: Rng {
+    let mut graph = Graph::new(n, g_type);
+    let directed = graph.g_type == GraphDirected;
+    let max_edges = if directed { n * n.saturating_sub(1) } else { n * n.saturating_sub(1) / 2 };
+    assert!(m <= max_edges, "A graph with {} nodes can't have {} edges", n, m);
+
+    // Sparse graphs draw random pairs until enough different ones are found, instead of
+    // listing all the possible ones
+    let pairs: Vec<(usize, usize)> = if 2 * m <= max_edges {
+        let mut chosen: HashSet<(usize, usize)> = HashSet::with_capacity(m);
+        let mut pairs = Vec::with_capacity(m);
+        while pairs.len() < m {
+            let (src, dst) = (rng.gen_range(0..n), rng.gen_range(0..n));
+            let pair = if directed { (src, dst) } else { (src.min(dst), src.max(dst)) };
+            if src != dst && chosen.insert(pair) {
+                pairs.push(pair);
+            }
+        }
+        pairs
+    } else {
+        node_pairs(n, &graph.g_type).choose_multiple(rng, m).cloned().collect()
+    };
+    for (src, dst) in pairs {
+        graph.create_edge(src, dst, weights.sample(rng));
+    }
+    graph
+}
+
+/// Returns an undirected random `d`-regular graph, without self loops nor parallel edges.
+///
+/// The edges are built by pairing the `d` stubs of each node at random, starting over
+/// whenever the remaining stubs can't be paired.
+///
+/// # Panics
+///
+/// If `d >= n` or `n * d` is odd, since no such graph exists.
+pub fn random_regular<T, R>(n: usize, d: usize, weights: &WeightDistribution<T::Weight>, rng: &mut R) -> Graph<T>
+    where T: Edge, T::Weight: SampleUniform, R: Rng {
+    assert!(d == 0 || d < n, "A {}-regular graph needs more than {} nodes", d, n);
+    assert!(n * d % 2 == 0, "A {}-regular graph can't have {} nodes", d, n);
+    const MAX_PICKS: usize = 100;
+
+    let pairs = 'pairing: loop {
+        let mut stubs: Vec<usize> = (0..n).flat_map(|v| std::iter::repeat(v).take(d)).collect();
+        // the pairs already linked, the lower node first
+        let mut linked: HashSet<(usize, usize)> = HashSet::with_capacity(n * d / 2);
+        let mut pairs: Vec<(usize, usize)> = Vec::with_capacity(n * d / 2);
+        while !stubs.is_empty() {
+            let suitable = |i: usize, j: usize| {
+                let (u, v) = (stubs[i], stubs[j]);
+                u != v && !linked.contains(&(u.min(v), u.max(v)))
+            };
+            // Random picks almost always succeed, the remaining pairs are only scanned when they don't
+            let picked = (0..MAX_PICKS)
+                .map(|_| (rng.gen_range(0..stubs.len()), rng.gen_range(0..stubs.len())))
+                .find(|(i, j)| suitable(*i, *j));
+            let (i, j) = match picked {
+                Some(pair) => pair,
+                None => {
+                    let candidates: Vec<(usize, usize)> = (0..stubs.len())
+                        .flat_map(|i| (i + 1..stubs.len()).map(move |j| (i, j)))
+                        .filter(|(i, j)| suitable(*i, *j))
+                        .collect();
+                    match candidates.choose(rng) {
+                        Some(pair) => *pair,
+                        None => continue 'pairing,
+                    }
+                }
+            };
+            let (i, j) = (i.max(j), i.min(j));
+            let (u, v) = (stubs[i], stubs[j]);
+            linked.insert((u.min(v), u.max(v)));
+            pairs.push((u, v));
+            stubs.swap_remove(i);
+            stubs.swap_remove(j);
+        }
+        break pairs;
+    };
+
+    let mut graph = Graph::new(n, GraphUndirected);
+    for (src, dst) in pairs {
+        graph.create_edge(src, dst, weights.sample(rng));
+    }
+    graph
+}
+
+/// Returns a `rows` x `columns` grid, node `r * columns + c` being the one in row `r` and
+/// column `c`, linked to the nodes on its right and below it.
+///
+/// In a directed grid each pair of neighbours is linked in both directions, with independent weights.
+pub fn grid<T, R>(rows: usize, columns: usize, g_type: GraphType, weights: &WeightDistribution<T::Weight>, rng: &mut R) -> Graph<T>
+    where T: Edge, T::Weight: SampleUniform, R: Rng {
+    let mut graph = Graph::new(rows * columns, g_type);
+    let directed = graph.g_type == GraphDirected;
+    for r in 0..rows {
+        for c in 0..columns {
+            let v = r * columns + c;
+            let mut neighbours: Vec<usize> = Vec::new();
+            if c + 1 < columns {
+                neighbours.push(v + 1);
+            }
+            if r + 1 < rows {
+                neighbours.push(v + columns);
+            }
+            for u in neighbours {
+                graph.create_edge(v, u, weights.sample(rng));
+                if directed {
+                    graph.create_edge(u, v, weights.sample(rng));
+                }
+            }
+        }
+    }
+    graph
+}
+
+/// Returns an undirected Barabási–Albert graph: starting from `m` isolated nodes, each new
+/// node is linked to `m` different existing nodes, chosen with a probability proportional
+/// to their degree.
+///
+/// # Panics
+///
+/// If `m` is 0 or not less than `n`.
+pub fn barabasi_albert<T, R>(n: usize, m: usize, weights: &WeightDistribution<T::Weight>, rng: &mut R) -> Graph<T>
+    where T: Edge, T::Weight: SampleUniform, R: Rng {
+    assert!(m >= 1 && m < n, "Each of the {} nodes can't be linked to {} older nodes", n, m);
+
+    let mut graph = Graph::new(n, GraphUndirected);
+    // Each node appears once per incident edge, so picking from it follows the degrees
+    let mut repeated: Vec<usize> = Vec::with_capacity(2 * n * m);
+    let mut targets: Vec<usize> = (0..m).collect();
+    for src in m..n {
+        for dst in targets.iter() {
+            graph.create_edge(src, *dst, weights.sample(rng));
+        }
+        repeated.extend_from_slice(&targets);
+        repeated.extend(std::iter::repeat(src).take(m));
+
+        targets.clear();
+        while targets.len() < m {
+            let candidate = *repeated.choose(rng).expect("The first node adds m edges");
+            if !targets.contains(&candidate) {
+                targets.push(candidate);
+            }
+        }
+    }
+    graph
+}
+
+/// Returns a random directed acyclic graph: the nodes are shuffled in a random topological
+/// order, then each edge going forward in that order is added with probability `p`.
+pub fn random_dag<T, R>(n: usize, p: f64, weights: &WeightDistribution<T::Weight>, rng: &mut R) -> Graph<T>
+    where T: Edge, T::Weight: SampleUniform, R: Rng {
+    let mut order: Vec<usize> = (0..n).collect();
+    order.shuffle(rng);
+
+    let mut graph = Graph::new(n, GraphDirected);
+    for_random_pairs(n, p, false, rng, |i, j, rng| graph.create_edge(order[i], order[j], weights.sample(rng)));
+    graph
+}
+
+/// Returns a directed copy of `graph` where some edges may be negative, but no cycle is.
+///
+/// Each node gets a random potential p(v) from `[0, max_potential)` and each edge (u, v) becomes
+/// w(u, v) + p(u) - p(v): along any cycle the potentials cancel out, so if the weights of
+/// `graph` are non-negative no negative cycle is created. Each undirected edge becomes two
+/// opposite arcs. Shortest paths are preserved, their length changing by p(source) - p(destination).
+pub fn with_negative_weights<T, R>(graph: &Graph<T>, max_potential: T::Weight, rng: &mut R) -> Graph<T>
+    where T: Edge, T::Weight: SampleUniform, R: Rng {
+    let zero = T::Weight::zero();
+    let potential: Vec<T::Weight> = (0..graph.n_nodes)
+        .map(|_| if max_potential > zero { rng.gen_range(zero..max_potential) } else { zero })
+        .collect();
+
+    let mut reweighted = Graph::new(graph.n_nodes, GraphDirected);
+    for edge in graph.unique_edges() {
+        let (src, dst) = (edge.source(), edge.destination());
+        reweighted.create_edge(src, dst, edge.weight() + potential[src] - potential[dst]);
+        if graph.g_type == GraphUndirected && src != dst {
+            reweighted.create_edge(dst, src, edge.weight() + potential[dst] - potential[src]);
+        }
+    }
+    reweighted
+}
+
+/// Calls `add` on each (source, destination) pair of different nodes with probability `p`,
+/// each undirected pair once with the lower node first, in increasing order.
+///
+/// Instead of drawing a number for each of the O(n^2) pairs, the number of pairs skipped
+/// before the next chosen one is drawn from a geometric distribution (Batagelj and Brandes),
+/// so the time only depends on n and on the number of chosen pairs.
+fn for_random_pairs<R, F>(n: usize, p: f64, directed: bool, rng: &mut R, mut add: F)
+    where R: Rng, F: FnMut(usize, usize, &mut R) {
+    if p <= 0.0 || n < 2 {
+        return;
+    }
+    let log_q = (1.0 - p).ln();
+    let skip = |rng: &mut R| if p >= 1.0 { 0 } else { ((1.0 - rng.gen::<f64>()).ln() / log_q) as usize };
+    // the pairs are laid out in rows: row v holds the sources of the undirected pairs ending
+    // in v, or the destinations of the directed pairs starting from v
+    let row_len = |v: usize| if directed { n - 1 } else { v };
+
+    let (mut v, mut i) = (0, skip(rng));
+    loop {
+        while v < n && i >= row_len(v) {
+            i -= row_len(v);
+            v += 1;
+        }
+        if v == n {
+            break;
+        }
+        if directed {
+            add(v, if i >= v { i + 1 } else { i }, rng);
+        } else {
+            add(i, v, rng);
+        }
+        i = i.saturating_add(1).saturating_add(skip(rng));
+    }
+}
+
+/// Returns every (source, destination) pair of different nodes an edge of a graph of type
+/// `g_type` can link, each undirected pair once.
+fn node_pairs(n: usize, g_type: &GraphType) -> Vec<(usize, usize)> {
+    let directed = *g_type == GraphDirected;
+    (0..n)
+        .flat_map(|src| (0..n).map(move |dst| (src, dst)))
+        .filter(|(src, dst)| if directed { src != dst } else { src < dst })
+        .collect()
+}
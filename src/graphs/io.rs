@@ -0,0 +1,120 @@
+use std::{fmt, fs};
+use std::io::{BufRead, BufReader, Write};
+use std::str::FromStr;
+use sscanf::{scanf};
+use crate::graphs::{Edge, Graph, NormalEdge, Weight};
+use crate::graphs::GraphType::{GraphDirected, GraphUndirected};
+
+/// Error returned when a graph file can't be read or is not well formatted.
+/// The line numbers start from 1.
+#[derive(Debug)]
+pub enum GraphParseError {
+    /// The file can't be opened or read
+    Io(std::io::Error),
+    /// The first line is missing or it is not in the "nodes edges type" form
+    BadHeader { line: usize, text: String },
+    /// An edge line is not in the "source destination weight" form
+    BadEdge { line: usize, text: String },
+    /// An edge line refers to a node the header doesn't declare
+    NodeOutOfRange { line: usize, text: String, node: usize, n_nodes: usize },
+    /// A line of a DIMACS or Matrix Market file is not in the `expected` form
+    BadFormat { line: usize, text: String, expected: &'static str },
+    /// The number of edge lines is different from the one declared in the header
+    EdgeCountMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for GraphParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GraphParseError::Io(e) => write!(f, "can't read the graph : {}", e),
+            GraphParseError::BadHeader { line, text } =>
+                write!(f, "line {} : expected \"nodes edges type\", found \"{}\"", line, text),
+            GraphParseError::BadEdge { line, text } =>
+                write!(f, "line {} : expected \"source destination weight\", found \"{}\"", line, text),
+            GraphParseError::NodeOutOfRange { line, text, node, n_nodes } =>
+                write!(f, "line {} : node {} out of range, the graph has {} nodes (\"{}\")", line, node, n_nodes, text),
+            GraphParseError::BadFormat { line, text, expected } =>
+                write!(f, "line {} : expected \"{}\", found \"{}\"", line, expected, text),
+            GraphParseError::EdgeCountMismatch { expected, found } =>
+                write!(f, "the header declares {} edges, but {} were found", expected, found),
+        }
+    }
+}
+
+impl From<std::io::Error> for GraphParseError {
+    fn from(e: std::io::Error) -> Self {
+        GraphParseError::Io(e)
+    }
+}
+
+/// Returns the graph described by the file at `path`, see `from_reader` for the format.
+pub fn from_file<W>(path : String) -> Result<Graph<NormalEdge<W>>, GraphParseError> where W: Weight + FromStr {
+    let file = fs::File::open(path)?;
+    from_reader(BufReader::new(file))
+}
+
+/// Returns the graph described by `text`, see `from_reader` for the format.
+#[allow(dead_code)]
+pub fn from_str<W>(text: &str) -> Result<Graph<NormalEdge<W>>, GraphParseError> where W: Weight + FromStr {
+    from_reader(text.as_bytes())
+}
+
+/// Returns the graph read from `reader`.
+///
+/// The first line holds the number of nodes, the number of edges and the type of the graph
+/// (1 for directed, any other value for undirected), each of the following lines holds the
+/// source, the destination and the weight of an edge. Blank lines are ignored.
+///
+/// The weights are parsed as `W`, so the same file can be read with integer or real weights.
+pub fn from_reader<W, R>(reader: R) -> Result<Graph<NormalEdge<W>>, GraphParseError> where W: Weight + FromStr, R: BufRead {
+    let mut header: Option<(usize, Graph<NormalEdge<W>>)> = None;
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line_number = i + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match header.as_mut() {
+            None => {
+                let (n_nodes, edges_count, g_type) = scanf!(line.trim(), "{} {} {}", usize, usize, usize)
+                    .map_err(|_| GraphParseError::BadHeader { line: line_number, text: line.clone() })?;
+                let graph_type = if g_type == 1 {GraphDirected} else {GraphUndirected};
+                header = Some((edges_count, Graph::new(n_nodes, graph_type)));
+            }
+            Some((_, graph)) => {
+                let (src, dst, weight) = scanf!(line.trim(), "{} {} {}", usize, usize, String).ok()
+                    .and_then(|(src, dst, weight)| Some((src, dst, weight.parse::<W>().ok()?)))
+                    .ok_or_else(|| GraphParseError::BadEdge { line: line_number, text: line.clone() })?;
+                for node in [src, dst] {
+                    if node >= graph.n_nodes() {
+                        return Err(GraphParseError::NodeOutOfRange { line: line_number, text: line, node, n_nodes: graph.n_nodes() });
+                    }
+                }
+                graph.create_edge(src, dst, weight);
+            }
+        }
+    }
+
+    let (n_edges, graph) = header.ok_or(GraphParseError::BadHeader { line: 1, text: String::new() })?;
+    if graph.n_edges != n_edges {
+        return Err(GraphParseError::EdgeCountMismatch { expected: n_edges, found: graph.n_edges });
+    }
+    Ok(graph)
+}
+
+impl<T> Graph<T> where T: Edge {
+
+    /// Writes the graph in the format read by `from_reader`: the "nodes edges type" header
+    /// followed by one "source destination weight" line per edge, each undirected edge
+    /// being written once.
+    #[allow(dead_code)]
+    pub fn write_to<W>(&self, writer: &mut W) -> std::io::Result<()> where W: Write {
+        let edges = self.unique_edges();
+        writeln!(writer, "{} {} {}", self.n_nodes, edges.len(), if self.g_type == GraphDirected { 1 } else { 0 })?;
+        for edge in edges {
+            writeln!(writer, "{} {} {}", edge.source(), edge.destination(), edge.weight())?;
+        }
+        Ok(())
+    }
+}
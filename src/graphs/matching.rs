@@ -0,0 +1,299 @@
+use std::collections::VecDeque;
+use std::fmt;
+use crate::graphs::{Distance, Edge, Graph, Weight};
+
+/// Error returned when a matching can't be computed
+#[derive(Debug, Clone)]
+pub enum MatchingError {
+    /// The graph contains an odd cycle, so its nodes can't be split in two sides
+    NotBipartite,
+    /// Some node can't be matched, so there is no perfect matching
+    NoPerfectMatching,
+    /// The potentials of the Hungarian Algorithm can't be represented by the weight type
+    WeightOverflow,
+}
+
+impl fmt::Display for MatchingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatchingError::NotBipartite => write!(f, "the graph is not bipartite"),
+            MatchingError::NoPerfectMatching => write!(f, "the graph has no perfect matching"),
+            MatchingError::WeightOverflow => write!(f, "the weights are too large for their type"),
+        }
+    }
+}
+
+/// Result of a matching computation
+#[allow(dead_code)]
+pub struct Matching<'a, T> where T: Edge {
+    /// Edges of the matching, no two of them sharing a node
+    pub edges: Vec<&'a T>,
+    /// For each node, the node it is matched to
+    pub mate: Vec<Option<usize>>,
+}
+
+#[allow(dead_code)]
+impl<'a, T> Matching<'a, T> where T: Edge {
+    fn new(n_nodes: usize, edges: Vec<&'a T>) -> Self {
+        let mut mate = vec![None; n_nodes];
+        for edge in edges.as_slice() {
+            mate[edge.source()] = Some(edge.destination());
+            mate[edge.destination()] = Some(edge.source());
+        }
+        Matching { edges, mate }
+    }
+
+    /// Returns the number of edges of the matching
+    pub fn size(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns the total weight of the edges of the matching, out of range if it can't be represented
+    pub fn weight(&self) -> Distance<T::Weight> {
+        Distance::sum(self.edges.iter().map(|edge| edge.weight()))
+    }
+}
+
+impl<T> Graph<T> where T: Edge {
+
+    /// Returns the side (false or true) of each node if the graph is bipartite, so that every
+    /// edge links two nodes of different sides, None otherwise. The sides are found coloring
+    /// each connected component with a BFS ( O(|E|+|V|) with |V| = number of nodes,
+    /// |E| = number of edges).
+    ///
+    /// The direction of the edges is ignored.
+    #[allow(dead_code)]
+    pub fn bipartition(&self) -> Option<Vec<bool>> {
+        let mut adjacent: Vec<Vec<usize>> = vec![Vec::new(); self.n_nodes];
+        for edge in self.edges.iter().flatten() {
+            adjacent[edge.source()].push(edge.destination());
+            adjacent[edge.destination()].push(edge.source());
+        }
+
+        let mut side: Vec<Option<bool>> = vec![None; self.n_nodes];
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        for root in 0..self.n_nodes {
+            if side[root].is_some() {
+                continue;
+            }
+            side[root] = Some(false);
+            queue.push_back(root);
+            while let Some(u) = queue.pop_front() {
+                for &v in adjacent[u].as_slice() {
+                    match side[v] {
+                        None => {
+                            side[v] = side[u].map(|s| !s);
+                            queue.push_back(v);
+                        }
+                        Some(s) if side[u] == Some(s) => return None,
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+
+        Some(side.into_iter().map(|s| s.expect("Every node is colored")).collect())
+    }
+
+    #[allow(dead_code)]
+    pub fn is_bipartite(&self) -> bool {
+        self.bipartition().is_some()
+    }
+
+    /// Returns a maximum cardinality matching of the bipartite graph with the Hopcroft-Karp
+    /// Algorithm ( O(|E|sqrt(|V|)) with |V| = number of nodes, |E| = number of edges).
+    ///
+    /// Each phase finds the shortest augmenting paths with a BFS from the free nodes of the
+    /// first side, stopping at the first layer that reaches a free node of the second side,
+    /// then augments along as many disjoint ones as possible with a DFS.
+    /// The direction of the edges is ignored.
+    #[allow(dead_code)]
+    pub fn hopcroft_karp(&self) -> Result<Matching<'_, T>, MatchingError> {
+        let side = self.bipartition().ok_or(MatchingError::NotBipartite)?;
+        let left: Vec<usize> = (0..self.n_nodes).filter(|v| !side[*v]).collect();
+        let mut adjacent: Vec<Vec<(usize, &T)>> = vec![Vec::new(); self.n_nodes];
+        for edge in self.unique_edges() {
+            let (src, dst) = (edge.source(), edge.destination());
+            if side[src] {
+                adjacent[dst].push((src, edge));
+            } else {
+                adjacent[src].push((dst, edge));
+            }
+        }
+
+        let mut mate: Vec<Option<(usize, &T)>> = vec![None; self.n_nodes];
+        let mut levels: Vec<Option<usize>> = vec![None; self.n_nodes];
+        loop {
+            let mut queue: VecDeque<usize> = VecDeque::new();
+            for &u in left.as_slice() {
+                levels[u] = if mate[u].is_none() { Some(0) } else { None };
+                if mate[u].is_none() {
+                    queue.push_back(u);
+                }
+            }
+            // level of the first layer with an edge to a free node: the deeper ones can't be on
+            // a shortest augmenting path
+            let mut free_level: Option<usize> = None;
+            while let Some(u) = queue.pop_front() {
+                if free_level.is_some() && levels[u] > free_level {
+                    break;
+                }
+                for &(v, _) in adjacent[u].as_slice() {
+                    match mate[v] {
+                        None => free_level = levels[u],
+                        Some((w, _)) if levels[w].is_none() => {
+                            levels[w] = levels[u].map(|level| level + 1);
+                            queue.push_back(w);
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+            let Some(free_level) = free_level else {
+                break;
+            };
+            for &u in left.as_slice() {
+                if mate[u].is_none() {
+                    augment(u, free_level, &adjacent, &mut levels, &mut mate);
+                }
+            }
+        }
+
+        let edges = left.iter().filter_map(|u| mate[*u].map(|(_, edge)| edge)).collect();
+        Ok(Matching::new(self.n_nodes, edges))
+    }
+
+    /// Returns a perfect matching of minimum total weight of the bipartite graph with the
+    /// Hungarian Algorithm ( O(|V|^3) with |V| = number of nodes).
+    ///
+    /// The nodes of the first side are the workers, the ones of the second side are the tasks,
+    /// each edge is a possible assignment weighing its cost. When two nodes are linked by more
+    /// than one edge only the cheapest one is considered.
+    /// The direction of the edges is ignored.
+    #[allow(dead_code)]
+    pub fn hungarian(&self) -> Result<Matching<'_, T>, MatchingError> {
+        let side = self.bipartition().ok_or(MatchingError::NotBipartite)?;
+        let left: Vec<usize> = (0..self.n_nodes).filter(|v| !side[*v]).collect();
+        let right: Vec<usize> = (0..self.n_nodes).filter(|v| side[*v]).collect();
+        if left.len() != right.len() {
+            return Err(MatchingError::NoPerfectMatching);
+        }
+        let n = left.len();
+        let mut index: Vec<usize> = vec![0; self.n_nodes];
+        for nodes in [&left, &right] {
+            for (i, v) in nodes.iter().enumerate() {
+                index[*v] = i;
+            }
+        }
+        let mut cost: Vec<Vec<Option<&T>>> = vec![vec![None; n]; n];
+        for edge in self.unique_edges() {
+            let (src, dst) = (edge.source(), edge.destination());
+            let (worker, task) = if side[src] { (index[dst], index[src]) } else { (index[src], index[dst]) };
+            if cost[worker][task].map_or(true, |cheapest| edge.weight() < cheapest.weight()) {
+                cost[worker][task] = Some(edge);
+            }
+        }
+
+        // Rows and columns are numbered from 1, the column 0 holding the worker being added.
+        // The column potentials are stored negated, so that none of the potentials is ever
+        // negative with non negative costs and unsigned weights can be used.
+        let zero = T::Weight::zero();
+        let mut row_potential: Vec<T::Weight> = vec![zero; n + 1];
+        let mut column_potential: Vec<T::Weight> = vec![zero; n + 1];
+        let mut assigned: Vec<usize> = vec![0; n + 1];
+        let mut way: Vec<usize> = vec![0; n + 1];
+        for worker in 1..=n {
+            assigned[0] = worker;
+            let mut column = 0;
+            let mut min_slack: Vec<Distance<T::Weight>> = vec![Distance::Infinite; n + 1];
+            let mut used = vec![false; n + 1];
+            loop {
+                used[column] = true;
+                let row = assigned[column];
+                let mut delta = Distance::Infinite;
+                let mut next = 0;
+                for j in 1..=n {
+                    if used[j] {
+                        continue;
+                    }
+                    if let Some(edge) = cost[row - 1][j - 1] {
+                        let slack = Distance::Finite(edge.weight()).add(column_potential[j]).sub(row_potential[row]);
+                        if !slack.is_finite() {
+                            return Err(MatchingError::WeightOverflow);
+                        }
+                        if slack < min_slack[j] {
+                            min_slack[j] = slack;
+                            way[j] = column;
+                        }
+                    }
+                    if min_slack[j] < delta {
+                        delta = min_slack[j];
+                        next = j;
+                    }
+                }
+                let delta = delta.finite().ok_or(MatchingError::NoPerfectMatching)?;
+                for j in 0..=n {
+                    if used[j] {
+                        row_potential[assigned[j]] = row_potential[assigned[j]].checked_add(delta)
+                            .ok_or(MatchingError::WeightOverflow)?;
+                        column_potential[j] = column_potential[j].checked_add(delta)
+                            .ok_or(MatchingError::WeightOverflow)?;
+                    } else {
+                        // delta is the smallest slack, so the difference stays in range
+                        min_slack[j] = min_slack[j].sub(delta);
+                    }
+                }
+                column = next;
+                if assigned[column] == 0 {
+                    break;
+                }
+            }
+            while column != 0 {
+                let previous = way[column];
+                assigned[column] = assigned[previous];
+                column = previous;
+            }
+        }
+
+        let edges = (1..=n)
+            .map(|j| cost[assigned[j] - 1][j - 1].expect("Only existing edges are assigned"))
+            .collect();
+        Ok(Matching::new(self.n_nodes, edges))
+    }
+}
+
+/// Looks for an augmenting path from the free node `root` following the BFS levels, for
+/// `Graph::hopcroft_karp`: only the nodes at `free_level` may end it on a free node. The nodes
+/// that lead nowhere lose their level.
+///
+/// The stack holds the path built so far, each node with the position of the next edge to try
+/// from it, and the matching is flipped along it once it reaches a free node.
+fn augment<'a, T>(root: usize, free_level: usize, adjacent: &[Vec<(usize, &'a T)>], levels: &mut [Option<usize>]
+                  , mate: &mut [Option<(usize, &'a T)>]) -> bool where T: Edge {
+    let mut stack: Vec<(usize, usize)> = vec![(root, 0)];
+    while let Some(top) = stack.last_mut() {
+        let (u, next) = *top;
+        let Some(&(v, _)) = adjacent[u].get(next) else {
+            levels[u] = None;
+            stack.pop();
+            continue;
+        };
+        top.1 += 1;
+        match mate[v] {
+            None if levels[u] == Some(free_level) => {
+                // each node of the stack is matched through the last edge it tried
+                for &(u, next) in stack.as_slice() {
+                    let (v, edge) = adjacent[u][next - 1];
+                    mate[u] = Some((v, edge));
+                    mate[v] = Some((u, edge));
+                }
+                return true;
+            }
+            Some((w, _)) if levels[w].is_some() && levels[w] == levels[u].map(|level| level + 1) => {
+                stack.push((w, 0));
+            }
+            _ => {}
+        }
+    }
+    false
+}
@@ -1,10 +1,39 @@
-use std::{fmt, fs};
-use std::io::{BufRead, BufReader, Error};
-use crate::graphs::Color::{BLACK, GREY, WHITE};
+use std::fmt;
 use crate::graphs::GraphType::{GraphDirected, GraphUndirected};
-use crate::MinHeap;
-use queues::{IsQueue, Queue};
-use sscanf::{scanf};
+use ndarray::Array2;
+
+mod biconnected;
+mod clique;
+mod cover;
+mod csr;
+mod dot;
+mod flow;
+mod formats;
+pub mod generators;
+mod io;
+mod matching;
+mod mst;
+mod results;
+mod scc;
+mod topological;
+mod traversal;
+mod weight;
+
+pub use csr::CsrGraph;
+#[cfg(test)]
+pub use dot::DotStyle;
+#[cfg(test)]
+pub use formats::{dimacs_from_reader, matrix_market_from_reader};
+pub use io::{from_file, GraphParseError};
+#[cfg(test)]
+pub use io::from_str;
+#[cfg(test)]
+pub use matching::MatchingError;
+pub use traversal::AdjacencyGraph;
+pub use weight::{Distance, Weight};
+pub use results::{AllShortestPathMatrix, BFSTree, DFSTree, DistanceMatrix, Path, PathTree, Printable, SearchTree, ShortestPathTree};
+#[cfg(test)]
+pub use results::{EdgeType, PathMatrix};
 
 #[derive(Debug, Clone)]
 pub struct NegativeEdgeError;
@@ -15,9 +44,59 @@ impl fmt::Display for NegativeEdgeError {
     }
 }
 
+/// Error returned when a procedure meets a cycle it can't handle:
+/// `cycle` holds the edges of the cycle, in order
+#[derive(Debug, Clone)]
+pub struct CycleError<T> where T: Edge {
+    pub cycle: Vec<T>,
+}
+
+impl<T> fmt::Display for CycleError<T> where T: Edge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the graph contains a cycle : ")?;
+        if let Some(first) = self.cycle.first() {
+            write!(f, "{}", first.source())?;
+        }
+        for edge in self.cycle.as_slice() {
+            write!(f, "->{}", edge.destination())?;
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when the nodes of a graph can't be sorted topologically
+#[derive(Debug, Clone)]
+pub enum TopologicalOrderError<T> where T: Edge {
+    /// The graph is undirected, so its edges don't order the nodes
+    UndirectedGraph,
+    /// The graph contains a cycle
+    Cycle(CycleError<T>),
+}
+
+impl<T> fmt::Display for TopologicalOrderError<T> where T: Edge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TopologicalOrderError::UndirectedGraph => write!(f, "this procedure only works on directed graphs"),
+            TopologicalOrderError::Cycle(cycle) => write!(f, "{}", cycle),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DirectedGraphError;
+
+impl fmt::Display for DirectedGraphError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "this procedure only works on undirected graphs")
+    }
+}
+
 
 pub trait Edge : Clone {
-    fn new(src: usize, dst: usize, weight: f32) -> Self;
+    /// Type of the weight of the edge
+    type Weight: Weight;
+
+    fn new(src: usize, dst: usize, weight: Self::Weight) -> Self;
 
     fn source(&self) -> usize;
 
@@ -27,14 +106,16 @@ pub trait Edge : Clone {
 
     fn set_destination(&mut self, destination : usize);
 
-    fn weight(&self) -> f32;
+    fn weight(&self) -> Self::Weight;
+
+    fn set_weight(&mut self, weight: Self::Weight);
 }
 
-#[derive(Clone)]
-pub struct NormalEdge{
+#[derive(Clone, Debug)]
+pub struct NormalEdge<W = f32> where W: Weight {
     src: usize,
     dst: usize,
-    weight: f32,
+    weight: W,
 }
 
 pub struct Graph<T> where T : Edge{
@@ -54,77 +135,16 @@ pub struct Graph<T> where T : Edge{
 }
 
 
-#[derive(PartialEq, Eq)]
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub enum GraphType {
     GraphUndirected,
     GraphDirected,
 }
 
-pub trait Printable {
-    fn print(&self, src : usize, dst : usize) -> ();
-}
-/// Represents the Shortest Path Tree: the first value is the list of the predecessors,
-/// the second is the distances list
-type ShortestPathTree<'a, T> = (Vec<Option<&'a T>>, Vec<f32>);
-/// Represents the Breath First Tree: the first value is the list of the predecessors,
-/// the second is the distances list
-type BFSTree<'a, T> = (Vec<Option<&'a T>>, Vec<i32>);
-/// Represents the Breath First Tree: the first value is the list of the predecessors,
-/// the second is the discover time list, the third is the finish time list
-type DFSTree<'a, T> = (Vec<Option<&'a T>>, Vec<usize>, Vec<usize>);
-/// Represent the matrix of the shortest path between all the pairs. The first value is the matrix of the predecessors,
-/// the second is the distances matrix
-type AllShortestPathMatrix<'a, T>  = (Vec<Vec<Option<&'a T>>>, Vec<Vec<f32>>);
-
-impl<T> Printable for ShortestPathTree<'_, T> where T : Edge {
-    fn print(&self, src : usize, dst : usize) -> () {
-        let (prevs, dists) = self;
-        print!("{} to {} | weight : {} | path : ", src, dst, dists[dst]);
-        print_path(prevs.clone(),src, dst);
-        println!();
-    }
-}
+impl<W> Edge for NormalEdge<W> where W: Weight {
+    type Weight = W;
 
-impl<T> Printable for BFSTree<'_, T>  where T : Edge {
-    fn print(&self, src: usize, dst: usize) -> () {
-        let (prevs, dists) = self;
-        print!("{} to {} | weight : {} | path : ", src, dst, dists[dst]);
-        print_path(prevs.clone(),src, dst);
-        println!();
-    }
-}
-
-impl<T> Printable for DFSTree<'_, T>  where T: Edge {
-    fn print(&self, _: usize, _: usize) -> () {
-        let (prevs, discover, finish) = self;
-        println!(" elem | prev | discover | finish |");
-        println!("------+------+----------+--------+");
-        for i in 0..prevs.len() {
-            match prevs[i] {
-                Some(prev) => {
-                    println!("  {}  |  {}  |    {}    |   {}   |", i, prev.source(), discover[i], finish[i])
-                },
-                None => {
-                    println!("  {}  |  {}  |    {}    |   {}   |", i, String::from("none"), discover[i], finish[i])
-                }
-            }
-
-        }
-    }
-}
-
-impl<T> Printable for AllShortestPathMatrix<'_, T>  where T : Edge {
-    fn print(&self, src: usize, dst: usize) -> () {
-        let (prevs, dists) = self;
-        print!("{} to {} | weight : {} | path : ", src, dst , dists[src][dst]);
-        print_all_pairs_sp(prevs.clone(), src, dst);
-        println!();
-    }
-}
-
-
-impl Edge for NormalEdge{
-    fn new(src: usize, dst: usize, weight: f32) -> Self {
+    fn new(src: usize, dst: usize, weight: W) -> Self {
         NormalEdge {
             src,
             dst,
@@ -148,9 +168,23 @@ impl Edge for NormalEdge{
         self.dst = destination
     }
 
-    fn weight(&self) -> f32 {
+    fn weight(&self) -> W {
         self.weight
     }
+
+    fn set_weight(&mut self, weight: W) {
+        self.weight = weight
+    }
+}
+
+impl<T> AdjacencyGraph<T> for Graph<T> where T: Edge {
+    fn n_nodes(&self) -> usize {
+        self.n_nodes
+    }
+
+    fn out_edges(&self, v: usize) -> impl Iterator<Item = T> + '_ {
+        self.edges[v].iter().cloned()
+    }
 }
 
 impl<T> Graph<T>  where T : Edge{
@@ -199,7 +233,7 @@ impl<T> Graph<T>  where T : Edge{
     /// * `src` - source of the edge
     /// * `dst` - destination of the edge
     /// * `weight` - weight of the edge
-    pub fn create_edge(&mut self, src: usize, dst: usize, weight: f32){
+    pub fn create_edge(&mut self, src: usize, dst: usize, weight: T::Weight){
         self.insert_edge(T::new(src, dst, weight));
         if self.g_type == GraphUndirected {
             self.insert_edge(T::new(dst, src, weight));
@@ -218,229 +252,298 @@ impl<T> Graph<T>  where T : Edge{
         self.n_edges += 1;
     }
 
+    /// Adds a node without edges and returns its index.
     #[allow(dead_code)]
-    pub fn print(&self){
-        println!("{}", if self.g_type == GraphDirected {String::from("DIRECTED")} else {String::from("UNDIRECTED")});
-        for i in 0..self.edges.len() {
-            print!("[ {}]", i);
-            for edge in self.edges[i].as_slice() {
-                print!(" -> ({}, {}, {})", edge.source(), edge.destination(), edge.weight());
-            }
-            println!();
-        }
+    pub fn add_node(&mut self) -> usize {
+        self.edges.push(Vec::new());
+        self.in_deg.push(0);
+        self.out_deg.push(0);
+        self.n_nodes += 1;
+        self.n_nodes - 1
     }
 
-    /// Returns the shortest path tree with the BFS Algorithm
-    /// ( O(|E|+|V|) with |V| = number of nodes, |E| = number of edges) of the given graph
-    /// from the give source.
-    ///
-    /// The first value is the list of the previous edge.
-    ///
-    /// The second value is the list of the distances from the source.
-    ///
-    ///
-    /// # Arguments
-    /// * `graph` - graph where to execute the algorithm
-    /// * `source` - source node of the shortest path tree
+    /// Removes node `v` along with all the edges entering or leaving it.
     ///
-    pub fn bfs(&self, source: usize) -> BFSTree<T> {
-
-        let mut colors: Vec<Color> = vec![WHITE; self.n_nodes];
-        let mut distances: Vec<i32> = vec![-1; self.n_nodes];
-        let mut prev_edge: Vec<Option<&T>> = vec![None; self.n_nodes];
-        let mut queue: Queue<usize> = Queue::new();
-
-        colors[source] = GREY;
-        distances[source] = 0;
-
-        let _ = queue.add(source);
-
-        while queue.size() != 0 {
-            let src = queue.remove().unwrap();
-            for edge in self.edges[src].as_slice() {
-                if colors[edge.destination()] == WHITE {
-                    colors[edge.destination()] = GREY;
-                    distances[edge.destination()] = distances[src] + 1;
-                    prev_edge[edge.destination()] = Some(edge);
-                    let _ = queue.add(edge.destination());
+    /// The nodes after `v` are shifted back by one, so node `u > v` becomes `u - 1`.
+    #[allow(dead_code)]
+    pub fn remove_node(&mut self, v: usize) {
+        let removed = if self.g_type == GraphDirected {
+            let entering = self.edges.iter().enumerate()
+                .filter(|(u, _)| *u != v)
+                .flat_map(|(_, adjacent)| adjacent.iter())
+                .filter(|edge| edge.destination() == v)
+                .count();
+            self.edges[v].len() + entering
+        } else {
+            // each self loop is stored twice in the list of v, any other edge once
+            let self_loops = self.edges[v].iter().filter(|edge| edge.destination() == v).count();
+            self.edges[v].len() - self_loops / 2
+        };
+
+        self.edges.remove(v);
+        self.n_nodes -= 1;
+        self.n_edges -= removed;
+        self.in_deg = vec![0; self.n_nodes];
+        self.out_deg = vec![0; self.n_nodes];
+        for adjacent in self.edges.iter_mut() {
+            adjacent.retain(|edge| edge.destination() != v);
+            for edge in adjacent.iter_mut() {
+                if edge.source() > v {
+                    edge.set_source(edge.source() - 1);
                 }
+                if edge.destination() > v {
+                    edge.set_destination(edge.destination() - 1);
+                }
+                self.out_deg[edge.source()] += 1;
+                self.in_deg[edge.destination()] += 1;
             }
-            colors[src] = BLACK;
         }
-
-        return (prev_edge, distances);
     }
 
-    /// Returns a spanning tree of the given graph with the Depth First Search
-    /// ( O(|E|+|V|) with |V| = number of nodes, |E| = number of edges)
-    ///
-    /// The first output is the predecessor list.
-    ///
-    /// The second output is the discover time list.
-    ///
-    /// The second output is the finish time list.
+    /// Removes an edge from `src` to `dst` and returns it, None if there is no such edge.
     ///
-    /// # Arguments
-    /// * `graph` - graph where to execute the algorithm
+    /// If the graph is undirected the mirrored copy is removed too, and `src` and `dst` can be
+    /// given in any order. Among parallel edges, the first one added is removed.
     #[allow(dead_code)]
-    pub fn dfs(&self) -> DFSTree<T>{
-        let mut discover: Vec<usize> = vec![0;self.n_nodes];
-        let mut finish: Vec<usize> = vec![0;self.n_nodes];
-        let mut color: Vec<Color> = vec![WHITE;self.n_nodes];
-        let mut prev: Vec<Option<&T>> = vec![None;self.n_nodes];
-        let mut time = 0;
-
-        for i in 0..self.n_nodes() {
-            if color[i] == WHITE {
-                self.dfs_visit(i, &mut discover, &mut finish, &mut color, &mut prev, &mut time);
-            }
+    pub fn remove_edge(&mut self, src: usize, dst: usize) -> Option<T> {
+        let edge = self.detach_edge(src, dst, None)?;
+        if self.g_type == GraphUndirected {
+            self.detach_edge(dst, src, Some(edge.weight()));
         }
-
-        (prev, discover, finish)
+        self.n_edges -= 1;
+        Some(edge)
     }
 
-    fn dfs_visit<'a>(&'a self, src : usize, discover: &mut Vec<usize>, finish: &mut Vec<usize>
-                     , color: &mut Vec<Color>, prev: &mut Vec<Option<&'a T>>, time: &mut usize){
-        *time += 1;
-        discover[src] = *time;
-        color[src] = GREY;
-
-        for edge in self.edges[src].as_slice() {
-            let dst = edge.destination();
-            if color[dst] == WHITE {
-                prev[dst] = Some(edge);
-                self.dfs_visit(dst, discover, finish, color, prev, time);
+    /// Sets the weight of an edge from `src` to `dst` (and of its mirrored copy if the graph
+    /// is undirected), returns false if there is no such edge.
+    #[allow(dead_code)]
+    pub fn set_weight(&mut self, src: usize, dst: usize, weight: T::Weight) -> bool {
+        let k = match self.find_edge(src, dst, None) {
+            Some(k) => k,
+            None => return false,
+        };
+        let old_weight = self.edges[src][k].weight();
+        self.edges[src][k].set_weight(weight);
+        if self.g_type == GraphUndirected {
+            // a self loop is stored twice in the same list, the first copy is already updated
+            let mirror = if src == dst {
+                self.edges[src].iter().skip(k + 1)
+                    .position(|edge| edge.destination() == src && edge.weight() == old_weight)
+                    .map(|i| i + k + 1)
+            } else {
+                self.find_edge(dst, src, Some(old_weight))
+            };
+            if let Some(m) = mirror {
+                self.edges[dst][m].set_weight(weight);
             }
         }
+        true
+    }
 
-        color[src] = BLACK;
-        *time += 1;
-        finish[src] = *time;
+    /// Returns the number of edges, counting each undirected edge once
+    #[allow(dead_code)]
+    pub fn n_edges(&self) -> usize {
+        self.n_edges
     }
 
-    /// Returns the shortest path tree with the Dijkstra Algorithm (O(|E|+|V|log(|V|) with |V| = number of nodes, |E| = number of edges) of the given graph
-    /// from the give source.
-    ///
-    /// # Arguments
-    /// * `graph` - graph where to execute the algorithm
-    /// * `source` - source node of the shortest path tree
-    pub fn dijkstra(&self, source: usize) -> Result<ShortestPathTree<T>, NegativeEdgeError> {
-        let mut distances: Vec<f32> = vec![f32::MAX - (1000.0 * 1000.0); self.n_nodes];
-        let mut heap = MinHeap::new();
-        let mut added = vec![false; self.n_nodes];
-        let mut prev_edge: Vec<Option<&T>> = vec![None; self.n_nodes];
-
-        distances[source] = 0.0;
-
-        for i in 0..self.n_nodes {
-            heap.insert(i, distances[i])
-        }
+    /// Returns the number of edges entering `v`
+    #[allow(dead_code)]
+    pub fn in_degree(&self, v: usize) -> usize {
+        self.in_deg[v]
+    }
 
-        while !heap.is_empty() {
-            let u = heap.delete_min();
-            added[u] = true;
-            for edge in self.edges[u].as_slice() {
-                let weight = edge.weight();
-                let dst = edge.destination();
-                if weight < 0.0 {
-                    return Err(NegativeEdgeError)
-                }
-                if !added[dst] && (distances[u] + weight < distances[dst]) {
-                    distances[dst] = distances[u] + weight;
-                    heap.change_prio(dst, distances[dst]);
-                    prev_edge[dst] = Some(edge);
-                }
-            }
-        }
+    /// Returns the number of edges leaving `v`
+    #[allow(dead_code)]
+    pub fn out_degree(&self, v: usize) -> usize {
+        self.out_deg[v]
+    }
 
-        return Ok((prev_edge, distances));
+    /// Returns the position in the list of `src` of the first edge going to `dst`,
+    /// with the given weight if any.
+    fn find_edge(&self, src: usize, dst: usize, weight: Option<T::Weight>) -> Option<usize> {
+        self.edges[src].iter()
+            .position(|edge| edge.destination() == dst && weight.map_or(true, |w| edge.weight() == w))
     }
 
-    /// Returns the shortest path tree with the Bellman-Ford Algorithm (O(|E||V|) with |V| = number of nodes, |E| = number of edges) of the given graph
-    /// from the give source.
-    ///
-    /// # Arguments
-    /// * `graph` - graph where to execute the algorithm
-    /// * `source` - source node of the shortest path tree
-    pub fn bellman_ford(&self, source: usize) -> Option<ShortestPathTree<T>> {
-        let mut distances: Vec<f32> = vec![f32::MAX - (1000.0 * 1000.0); self.n_nodes];
-        let mut prev_edge: Vec<Option<&T>> = vec![None; self.n_nodes];
-
-        distances[source] = 0.0;
-
-        for _ in 0..self.n_nodes-1 {
-            for i in 0..self.n_nodes {
-                for edge in self.edges[i].as_slice() {
-                    if distances[edge.destination()] > distances[edge.source()] + edge.weight() {
-                        distances[edge.destination()] = distances[edge.source()] + edge.weight();
-                        prev_edge[edge.destination()] = Some(edge);
+    /// Removes the stored edge found by `find_edge`, keeping the degrees up to date.
+    fn detach_edge(&mut self, src: usize, dst: usize, weight: Option<T::Weight>) -> Option<T> {
+        let k = self.find_edge(src, dst, weight)?;
+        self.in_deg[dst] -= 1;
+        self.out_deg[src] -= 1;
+        Some(self.edges[src].remove(k))
+    }
+
+    /// Returns every edge of the graph once: the edges of an undirected graph are stored in
+    /// both directions, so only the copy going from the lower node to the higher one is kept
+    /// (and one every two copies of a self loop).
+    fn unique_edges(&self) -> Vec<&T> {
+        let mut edges: Vec<&T> = Vec::with_capacity(self.n_edges);
+        for adjacent in self.edges.iter() {
+            let mut self_loops = 0;
+            for edge in adjacent.as_slice() {
+                if self.g_type == GraphDirected || edge.source() < edge.destination() {
+                    edges.push(edge);
+                } else if edge.source() == edge.destination() {
+                    if self_loops % 2 == 0 {
+                        edges.push(edge);
                     }
+                    self_loops += 1;
                 }
             }
         }
+        edges
+    }
 
-        for i in 0..self.n_nodes {
-            for edge in self.edges[i].as_slice(){
-                if distances[edge.destination()] > (distances[edge.source()] + edge.weight()) {
-                    println!("{}>{}",distances[edge.destination()], distances[edge.source()] + edge.weight());
-                    return None
-                }
+    #[allow(dead_code)]
+    pub fn print(&self){
+        println!("{}", if self.g_type == GraphDirected {String::from("DIRECTED")} else {String::from("UNDIRECTED")});
+        for i in 0..self.edges.len() {
+            print!("[ {}]", i);
+            for edge in self.edges[i].as_slice() {
+                print!(" -> ({}, {}, {})", edge.source(), edge.destination(), edge.weight());
             }
+            println!();
         }
-        return Some((prev_edge, distances));
     }
 
     /// Returns the shortest path for each for each pair with the Floyd-Warshall algorithm (O(|V^3| with |V| number of nodes.
-    pub fn floyd_warshall(&self) -> AllShortestPathMatrix<T> {
+    pub fn floyd_warshall(&self) -> AllShortestPathMatrix<'_, T> {
         let n = self.n_nodes();
-        let mut dists : Vec<Vec<f32>> = vec![vec![f32::MAX - (1000.0 * 1000.0);n]; n];
+        let mut dists : Vec<Vec<Distance<T::Weight>>> = vec![vec![Distance::Infinite;n]; n];
         let mut prevs: Vec<Vec<Option<&T>>> = vec![vec![None; n];n];
 
-        for i in 0..n {
-            dists[i][i] = 0.0;
-            for edge in self.edges[i].as_slice() {
-                prevs[edge.source()][edge.destination()] = Some(edge);
-                dists[edge.source()][edge.destination()] = edge.weight();
+        for (i, row) in dists.iter_mut().enumerate() {
+            row[i] = Distance::Finite(T::Weight::zero());
+        }
+        // keep the cheapest of the parallel edges, a self loop only counts if it is negative
+        for edge in self.edges.iter().flatten() {
+            let (src, dst) = (edge.source(), edge.destination());
+            if Distance::Finite(edge.weight()) < dists[src][dst] {
+                dists[src][dst] = Distance::Finite(edge.weight());
+                prevs[src][dst] = Some(edge);
             }
         }
 
+        // the row and the column of k don't change during the k-th step, so the matrices
+        // can be updated in place
         for k in 0..n {
-            let old_dists = dists.clone();
-            let old_prevs = prevs.clone();
             for i in 0..n {
                 for j in 0..n {
-                    if j != k && old_dists[i][j] > old_dists[i][k] + old_dists[k][j] {
-                        dists[i][j] = old_dists[i][k] + old_dists[k][j];
-                        prevs[i][j] = old_prevs[k][j];
+                    let through_k = dists[i][k].join(dists[k][j]);
+                    if j != k && dists[i][j] > through_k {
+                        dists[i][j] = through_k;
+                        prevs[i][j] = prevs[k][j];
                     }
                 }
             }
         }
-        (prevs, dists)
+        AllShortestPathMatrix::new(prevs, dists)
     }
 
-    ///Missing Doc
+    /// Returns the shortest path for each pair with the Floyd-Warshall algorithm (O(|V^3| with |V| number of nodes),
+    /// storing the matrices in two contiguous row-major arrays updated in place.
     ///
+    /// Unlike `floyd_warshall`, it also reports the nodes lying on a negative cycle: the distances
+    /// of the pairs whose paths can pass through them are meaningless.
     #[allow(dead_code)]
-    pub fn approx_vertex_cover(&self) -> Vec<&T> {
-        let mut cover : Vec<&T> = Vec::new();
-        let mut covered : Vec<bool> = vec![false; self.n_nodes];
-        let edges = &self.edges;
-        for i in 0..self.n_nodes{
-            if !covered[i] {
-                for edge in &edges[i] {
-                    if !covered[edge.destination()] {
-                        cover.push(edge);
-                        covered[edge.destination()] = true;
-                        covered[i] = true;
-                        break ;
+    pub fn floyd_warshall_matrix(&self) -> DistanceMatrix<'_, T> {
+        let n = self.n_nodes();
+        let mut dists: Array2<Distance<T::Weight>> = Array2::from_elem((n, n), Distance::Infinite);
+        let mut prevs: Array2<Option<&T>> = Array2::from_elem((n, n), None);
+
+        for i in 0..n {
+            dists[[i, i]] = Distance::Finite(T::Weight::zero());
+        }
+        for edge in self.edges.iter().flatten() {
+            let (src, dst) = (edge.source(), edge.destination());
+            if Distance::Finite(edge.weight()) < dists[[src, dst]] {
+                dists[[src, dst]] = Distance::Finite(edge.weight());
+                prevs[[src, dst]] = Some(edge);
+            }
+        }
+
+        for k in 0..n {
+            for i in 0..n {
+                let through_k = dists[[i, k]];
+                if !through_k.is_reachable() {
+                    continue;
+                }
+                for j in 0..n {
+                    let candidate = through_k.join(dists[[k, j]]);
+                    if candidate < dists[[i, j]] {
+                        dists[[i, j]] = candidate;
+                        prevs[[i, j]] = prevs[[k, j]];
                     }
                 }
             }
         }
-        cover
+
+        let negative_cycle_nodes = (0..n).filter(|i| dists[[*i, *i]] < Distance::Finite(T::Weight::zero())).collect();
+        DistanceMatrix { dists, prevs, negative_cycle_nodes }
     }
 
+    /// Returns the shortest path for each pair with the Johnson algorithm
+    /// (O(|V||E|log(|V|)) with |V| = number of nodes, |E| = number of edges), much faster than
+    /// Floyd-Warshall on sparse graphs.
+    ///
+    /// The edges are reweighted to non-negative values with the potentials computed by Bellman-Ford
+    /// from a virtual node linked to every other one, then Dijkstra runs from each node.
+    /// If a potential, a reweighted edge or a reweighted distance doesn't fit the weight type,
+    /// the distances are computed by `floyd_warshall` instead.
+    ///
+    /// If the graph contains a negative cycle, the error holds its edges.
+    #[allow(dead_code)]
+    pub fn johnson(&self) -> Result<AllShortestPathMatrix<'_, T>, CycleError<T>> {
+        let n = self.n_nodes();
+        let zero = T::Weight::zero();
+        let mut extended: Graph<T> = Graph::new(n + 1, GraphDirected);
+        for edge in self.edges.iter().flatten() {
+            extended.insert_edge(edge.clone());
+        }
+        for i in 0..n {
+            extended.insert_edge(T::new(n, i, zero));
+        }
+
+        // the virtual node has no incoming edges, so a negative cycle is made of original edges
+        let spt = extended.bellman_ford(n)?;
+        let Some(potentials) = (0..=n).map(|i| spt.distance(i)).collect::<Option<Vec<T::Weight>>>() else {
+            return Ok(self.floyd_warshall());
+        };
+        // potentials are never positive and the reweighted edges never negative, so adding the
+        // potential of the source first always stays in range
+        let reweight = |edge: &T| Distance::Finite(edge.weight()).add(potentials[edge.source()]).sub(potentials[edge.destination()]);
+        if self.edges.iter().flatten().any(|edge| !reweight(edge).is_finite()) {
+            return Ok(self.floyd_warshall());
+        }
+
+        let mut prevs: Vec<Vec<Option<&T>>> = Vec::with_capacity(n);
+        let mut dists: Vec<Vec<Distance<T::Weight>>> = Vec::with_capacity(n);
+        for i in 0..n {
+            let spt = traversal::dijkstra_by(self, i, |edge| {
+                let reweighted = reweight(edge).finite().expect("Every reweighted edge is in range");
+                if reweighted < zero { zero } else { reweighted }
+            }).expect("Reweighted edges are non-negative");
+            if (0..n).any(|j| spt.is_reachable(j) && spt.distance(j).is_none()) {
+                return Ok(self.floyd_warshall());
+            }
+            // the tree holds copies of the edges: any stored edge with the same ends and weight
+            // is as short
+            prevs.push((0..n)
+                .map(|j| spt.predecessor(j).map(|prev| {
+                    let k = self.find_edge(prev.source(), j, Some(prev.weight())).expect("The edge belongs to the graph");
+                    &self.edges[prev.source()][k]
+                }))
+                .collect());
+            // as for the edges, adding the potential of the destination first stays in range
+            dists.push((0..n)
+                .map(|j| match spt.distance(j) {
+                    Some(d) => Distance::Finite(d).add(potentials[j]).sub(potentials[i]),
+                    None => Distance::Infinite,
+                })
+                .collect());
+        }
+        Ok(AllShortestPathMatrix::new(prevs, dists))
+    }
 }
 
 #[derive(PartialEq, Eq, Clone)]
@@ -450,76 +553,25 @@ enum Color {
     GREY,
 }
 
-pub fn print_path<T>(pred: Vec<Option<&T>>, src: usize, dst: usize) where T : Edge{
-    if src == dst {
-        print!("{}", src)
-    } else {
-        match pred[dst] {
-            Some(edge) => {
-                print_path(pred,src, edge.source());
-                print!("->{}", dst);
-            }
-            None => println!("-1"),
-        }
+/// Returns the cycle found following the predecessors backward from `node`, None if the walk
+/// reaches a node without predecessor instead.
+///
+/// After |V| steps back the walk is surely inside the cycle, then it's followed until it closes.
+fn predecessors_cycle<T>(pred: &[Option<T>], node: usize) -> Option<Vec<T>> where T: Edge {
+    let mut start = node;
+    for _ in 0..pred.len() {
+        start = pred[start].as_ref()?.source();
     }
-}
-
-pub fn print_all_pairs_sp<T>(prevs: Vec<Vec<Option<&T>>>, src : usize, dst: usize)where T : Edge {
-    if src == dst {
-        print!("{}", src)
-    } else {
-        match prevs[src][dst] {
-            Some(edge) => {
-                print_all_pairs_sp(prevs, src, edge.source());
-                print!("->{}", dst)
-            },
-            None => print!("-1")
+    let mut cycle: Vec<T> = Vec::new();
+    let mut node = start;
+    loop {
+        let edge = pred[node].as_ref().expect("Nodes on a cycle have a predecessor");
+        node = edge.source();
+        cycle.push(edge.clone());
+        if node == start {
+            break;
         }
     }
-}
-
-pub fn from_file(path : String) -> Result<Graph<NormalEdge>, Error>{
-    let file = fs::File::open(path)?;
-    let buff_reader = BufReader::new(file);
-    let mut first = true;
-    let mut n_edges : usize = 0;
-    let mut graph = Graph::new(0, GraphDirected);
-    for line in buff_reader.lines() {
-        if first {
-            let (n_nodes, edges_count, g_type) = match line {
-                Ok(line) => scanf!(line, "{} {} {}", usize, usize, usize).expect("First line wrongly formatted"),
-                //I don't really now when this can happen
-                Err(e)  => {
-                    return Err(Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        e.to_string(),
-                    ));
-                }
-            };
-            n_edges = edges_count;
-            let graph_type = if g_type == 1 {GraphDirected} else {GraphUndirected};
-            graph = Graph::new(n_nodes, graph_type);
-            first = false;
-        } else {
-            let (src, dst, weight) = match line {
-                Ok(line) => scanf!(line, "{} {} {}", usize, usize, f32).expect("Line not formatted properly"),
-                //I don't really now when this can happen
-                Err(e)  => {
-                    return Err(Error::new(
-                        std::io::ErrorKind::InvalidInput,
-                        e.to_string(),
-                    ));
-                }
-            };
-            graph.create_edge(src, dst, weight);
-        }
-    }
-
-    if graph.n_edges != n_edges {
-        return Err(Error::new(
-            std::io::ErrorKind::InvalidInput,
-            "Lines prompted not matching grid size",
-        ));
-    }
-    Ok(graph)
+    cycle.reverse();
+    Some(cycle)
 }
@@ -0,0 +1,95 @@
+use crate::disjoint_set::DisjointSet;
+use crate::graphs::{DirectedGraphError, Distance, Edge, Graph};
+use crate::graphs::GraphType::GraphDirected;
+use crate::MinHeap;
+
+/// Represents a minimum spanning tree (or forest, if the graph is not connected):
+/// the chosen edges and their total weight
+#[derive(Debug)]
+pub struct SpanningTree<'a, T> where T: Edge {
+    edges: Vec<&'a T>,
+    weight: Distance<T::Weight>,
+}
+
+#[allow(dead_code)]
+impl<'a, T> SpanningTree<'a, T> where T: Edge {
+    fn new(edges: Vec<&'a T>) -> Self {
+        let weight = Distance::sum(edges.iter().map(|edge| edge.weight()));
+        SpanningTree { edges, weight }
+    }
+
+    /// Returns the edges of the tree, each undirected edge in a single direction
+    pub fn edges(&self) -> &[&'a T] {
+        &self.edges
+    }
+
+    /// Returns the total weight of the edges, out of range if it can't be represented
+    pub fn weight(&self) -> Distance<T::Weight> {
+        self.weight
+    }
+}
+
+impl<T> Graph<T> where T: Edge {
+
+    /// Returns the minimum spanning forest of the graph with the Kruskal Algorithm
+    /// (O(|E|log(|E|)) with |E| = number of edges).
+    ///
+    /// Each undirected edge is considered once, even if it's stored in both directions.
+    #[allow(dead_code)]
+    pub fn kruskal(&self) -> Result<SpanningTree<'_, T>, DirectedGraphError> {
+        if self.g_type == GraphDirected {
+            return Err(DirectedGraphError);
+        }
+        let mut edges: Vec<&T> = self.edges.iter()
+            .flatten()
+            .filter(|edge| edge.source() < edge.destination())
+            .collect();
+        edges.sort_by(|a, b| a.weight().partial_cmp(&b.weight()).expect("Edge weights can't be NaN"));
+
+        let mut sets = DisjointSet::new(self.n_nodes);
+        let mut tree: Vec<&T> = Vec::new();
+        for edge in edges {
+            if sets.union(edge.source(), edge.destination()) {
+                tree.push(edge);
+            }
+        }
+        Ok(SpanningTree::new(tree))
+    }
+
+    /// Returns the minimum spanning forest of the graph with the Prim Algorithm
+    /// (O(|E|log(|V|)) with |V| = number of nodes, |E| = number of edges).
+    ///
+    /// When the heap only holds unreachable nodes, the cheapest one becomes the root of a new tree.
+    #[allow(dead_code)]
+    pub fn prim(&self) -> Result<SpanningTree<'_, T>, DirectedGraphError> {
+        if self.g_type == GraphDirected {
+            return Err(DirectedGraphError);
+        }
+        let mut keys: Vec<Distance<T::Weight>> = vec![Distance::Infinite; self.n_nodes];
+        let mut heap = MinHeap::new();
+        let mut added = vec![false; self.n_nodes];
+        let mut prev_edge: Vec<Option<&T>> = vec![None; self.n_nodes];
+        let mut tree: Vec<&T> = Vec::new();
+
+        for (i, key) in keys.iter().enumerate() {
+            heap.insert(i, *key)
+        }
+
+        while !heap.is_empty() {
+            let u = heap.delete_min();
+            added[u] = true;
+            if let Some(edge) = prev_edge[u] {
+                tree.push(edge);
+            }
+            for edge in self.edges[u].as_slice() {
+                let dst = edge.destination();
+                if !added[dst] && Distance::Finite(edge.weight()) < keys[dst] {
+                    keys[dst] = Distance::Finite(edge.weight());
+                    heap.change_prio(dst, keys[dst]);
+                    prev_edge[dst] = Some(edge);
+                }
+            }
+        }
+        Ok(SpanningTree::new(tree))
+    }
+}
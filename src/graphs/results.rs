@@ -0,0 +1,432 @@
+use std::collections::HashMap;
+use std::fmt;
+use ndarray::Array2;
+use crate::graphs::{Distance, Edge};
+
+pub trait Printable {
+    fn print(&self, src : usize, dst : usize);
+}
+
+/// Represents the Shortest Path Tree rooted in `source`: for each node, the last edge of its
+/// shortest path and its distance from the source
+#[derive(Debug)]
+pub struct ShortestPathTree<T> where T: Edge {
+    source: usize,
+    prevs: Vec<Option<T>>,
+    dists: Vec<Distance<T::Weight>>,
+}
+
+/// Represents the part of the Shortest Path Tree rooted in `source` explored by an A* search:
+/// for each discovered node, the last edge of the best path found to it and its weight
+#[derive(Debug)]
+pub struct SearchTree<T> where T: Edge {
+    source: usize,
+    prevs: HashMap<usize, T>,
+    dists: HashMap<usize, Distance<T::Weight>>,
+}
+
+/// Represents the Breath First Tree rooted in `source`: for each node, the edge it was
+/// discovered from and its distance (number of edges) from the source
+#[derive(Debug)]
+pub struct BFSTree<T> where T: Edge {
+    source: usize,
+    prevs: Vec<Option<T>>,
+    dists: Vec<i32>,
+}
+
+/// Represents the Depth First Forest: for each node, the edge it was discovered from,
+/// its discover time and its finish time
+#[derive(Debug)]
+pub struct DFSTree<T> where T: Edge {
+    prevs: Vec<Option<T>>,
+    discover: Vec<usize>,
+    finish: Vec<usize>,
+}
+
+/// Kind of an edge with respect to a Depth First Forest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeType {
+    /// Edge a node was discovered from
+    Tree,
+    /// Edge going to an ancestor of its source (or to the source itself)
+    Back,
+    /// Edge going to a descendant of its source which is not a tree edge
+    Forward,
+    /// Any other edge: its destination was already finished when its source was discovered
+    Cross,
+}
+
+/// Represents the shortest paths between all the pairs: for each pair, the last edge of the
+/// path and its weight
+#[derive(Debug)]
+pub struct AllShortestPathMatrix<'a, T> where T: Edge {
+    prevs: Vec<Vec<Option<&'a T>>>,
+    dists: Vec<Vec<Distance<T::Weight>>>,
+}
+
+/// Represents the shortest paths between all the pairs stored in row-major arrays:
+/// `prevs[[i, j]]` is the last edge of the path from i to j, `dists[[i, j]]` is its weight
+#[allow(dead_code)]
+pub struct DistanceMatrix<'a, T> where T: Edge {
+    pub dists: Array2<Distance<T::Weight>>,
+    pub prevs: Array2<Option<&'a T>>,
+    /// Nodes lying on a negative cycle, sorted
+    pub negative_cycle_nodes: Vec<usize>,
+}
+
+/// A path of the graph: the sequence of the crossed nodes and the sequence of the edges
+/// connecting them
+#[derive(Clone, Debug)]
+pub struct Path<E> {
+    nodes: Vec<usize>,
+    edges: Vec<E>,
+}
+
+/// Results that hold a tree of paths starting from a single source
+pub trait PathTree<T> where T: Edge {
+    /// Returns the path from the source to `dst`, None if `dst` can't be reached
+    fn path_to(&self, dst: usize) -> Option<Path<&T>>;
+}
+
+/// Results that hold a path for each pair of nodes
+pub trait PathMatrix<'a, T> where T: Edge {
+    /// Returns the path from `src` to `dst`, None if `dst` can't be reached from `src`
+    fn path_between(&self, src: usize, dst: usize) -> Option<Path<&'a T>>;
+}
+
+#[allow(dead_code)]
+impl<T> ShortestPathTree<T> where T: Edge {
+    pub(crate) fn new(source: usize, prevs: Vec<Option<T>>, dists: Vec<Distance<T::Weight>>) -> Self {
+        ShortestPathTree { source, prevs, dists }
+    }
+
+    pub fn source(&self) -> usize {
+        self.source
+    }
+
+    /// Returns the distance of `v` from the source, None if `v` can't be reached or if its
+    /// distance is out of the range of the weight type
+    pub fn distance(&self, v: usize) -> Option<T::Weight> {
+        self.dists[v].finite()
+    }
+
+    /// Returns the last edge of the shortest path to `v`, None for the source and the unreachable nodes
+    pub fn predecessor(&self, v: usize) -> Option<&T> {
+        self.prevs[v].as_ref()
+    }
+
+    pub fn is_reachable(&self, v: usize) -> bool {
+        self.dists[v].is_reachable()
+    }
+}
+
+#[allow(dead_code)]
+impl<T> SearchTree<T> where T: Edge {
+    pub(crate) fn new(source: usize, prevs: HashMap<usize, T>, dists: HashMap<usize, Distance<T::Weight>>) -> Self {
+        SearchTree { source, prevs, dists }
+    }
+
+    pub fn source(&self) -> usize {
+        self.source
+    }
+
+    /// Returns the weight of the best path found to `v`, None if the search didn't reach `v`
+    /// or if its distance is out of the range of the weight type
+    pub fn distance(&self, v: usize) -> Option<T::Weight> {
+        self.dists.get(&v).and_then(|dist| dist.finite())
+    }
+
+    /// Returns the last edge of the best path found to `v`, None for the source and the nodes
+    /// the search didn't reach
+    pub fn predecessor(&self, v: usize) -> Option<&T> {
+        self.prevs.get(&v)
+    }
+
+    pub fn is_reachable(&self, v: usize) -> bool {
+        self.dists.contains_key(&v)
+    }
+}
+
+#[allow(dead_code)]
+impl<T> BFSTree<T> where T: Edge {
+    pub(crate) fn new(source: usize, prevs: Vec<Option<T>>, dists: Vec<i32>) -> Self {
+        BFSTree { source, prevs, dists }
+    }
+
+    pub fn source(&self) -> usize {
+        self.source
+    }
+
+    /// Returns the number of edges between the source and `v`, None if `v` can't be reached
+    pub fn distance(&self, v: usize) -> Option<usize> {
+        if self.is_reachable(v) { Some(self.dists[v] as usize) } else { None }
+    }
+
+    /// Returns the edge `v` was discovered from, None for the source and the unreachable nodes
+    pub fn predecessor(&self, v: usize) -> Option<&T> {
+        self.prevs[v].as_ref()
+    }
+
+    pub fn is_reachable(&self, v: usize) -> bool {
+        self.dists[v] >= 0
+    }
+}
+
+#[allow(dead_code)]
+impl<T> DFSTree<T> where T: Edge {
+    pub(crate) fn new(prevs: Vec<Option<T>>, discover: Vec<usize>, finish: Vec<usize>) -> Self {
+        DFSTree { prevs, discover, finish }
+    }
+
+    /// Returns the edge `v` was discovered from, None for the roots of the forest
+    pub fn predecessor(&self, v: usize) -> Option<&T> {
+        self.prevs[v].as_ref()
+    }
+
+    pub fn discover(&self, v: usize) -> usize {
+        self.discover[v]
+    }
+
+    pub fn finish(&self, v: usize) -> usize {
+        self.finish[v]
+    }
+
+    /// Returns the kind of `edge`, which must belong to the graph the forest was computed on,
+    /// comparing the discover and finish times of its nodes.
+    ///
+    /// In an undirected graph every edge is stored in both directions: the second copy of a
+    /// tree edge is a back edge, and there are no forward or cross edges.
+    /// The edges are told apart only by their ends, so all the parallel copies of a tree edge
+    /// are tree edges.
+    pub fn edge_type(&self, edge: &T) -> EdgeType {
+        let (u, v) = (edge.source(), edge.destination());
+        if self.prevs[v].as_ref().is_some_and(|prev| prev.source() == u) {
+            EdgeType::Tree
+        } else if self.discover[v] <= self.discover[u] && self.finish[u] <= self.finish[v] {
+            EdgeType::Back
+        } else if self.discover[u] < self.discover[v] && self.finish[v] < self.finish[u] {
+            EdgeType::Forward
+        } else {
+            EdgeType::Cross
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl<'a, T> AllShortestPathMatrix<'a, T> where T: Edge {
+    pub(crate) fn new(prevs: Vec<Vec<Option<&'a T>>>, dists: Vec<Vec<Distance<T::Weight>>>) -> Self {
+        AllShortestPathMatrix { prevs, dists }
+    }
+
+    /// Returns the distance of `dst` from `src`, None if `dst` can't be reached from `src` or
+    /// if its distance is out of the range of the weight type
+    pub fn distance(&self, src: usize, dst: usize) -> Option<T::Weight> {
+        self.dists[src][dst].finite()
+    }
+
+    /// Returns the last edge of the shortest path from `src` to `dst`
+    pub fn predecessor(&self, src: usize, dst: usize) -> Option<&'a T> {
+        self.prevs[src][dst]
+    }
+
+    pub fn is_reachable(&self, src: usize, dst: usize) -> bool {
+        self.dists[src][dst].is_reachable()
+    }
+}
+
+#[allow(dead_code)]
+impl<E> Path<E> {
+    pub fn nodes(&self) -> &[usize] {
+        self.nodes.as_slice()
+    }
+
+    pub fn edges(&self) -> &[E] {
+        self.edges.as_slice()
+    }
+
+    /// Returns the number of edges of the path
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+}
+
+impl<'a, T> Path<&'a T> where T: Edge {
+    /// Returns the path starting from `start` and following `edges`
+    pub(crate) fn from_edges(start: usize, edges: Vec<&'a T>) -> Self {
+        let mut nodes = vec![start];
+        nodes.extend(edges.iter().map(|edge| edge.destination()));
+        Path { nodes, edges }
+    }
+
+    /// Returns the total weight of the edges of the path, out of range if it can't be represented
+    #[allow(dead_code)]
+    pub fn weight(&self) -> Distance<T::Weight> {
+        Distance::sum(self.edges.iter().map(|edge| edge.weight()))
+    }
+}
+
+impl<T> PathTree<T> for ShortestPathTree<T> where T: Edge {
+    fn path_to(&self, dst: usize) -> Option<Path<&T>> {
+        if !self.is_reachable(dst) {
+            return None;
+        }
+        Some(tree_path(|v| self.prevs[v].as_ref(), dst))
+    }
+}
+
+impl<T> PathTree<T> for SearchTree<T> where T: Edge {
+    fn path_to(&self, dst: usize) -> Option<Path<&T>> {
+        if !self.is_reachable(dst) {
+            return None;
+        }
+        Some(tree_path(|v| self.prevs.get(&v), dst))
+    }
+}
+
+impl<T> PathTree<T> for BFSTree<T> where T: Edge {
+    fn path_to(&self, dst: usize) -> Option<Path<&T>> {
+        if !self.is_reachable(dst) {
+            return None;
+        }
+        Some(tree_path(|v| self.prevs[v].as_ref(), dst))
+    }
+}
+
+impl<'a, T> PathMatrix<'a, T> for AllShortestPathMatrix<'a, T> where T: Edge {
+    fn path_between(&self, src: usize, dst: usize) -> Option<Path<&'a T>> {
+        let mut edges: Vec<&T> = Vec::new();
+        let mut node = dst;
+        while node != src {
+            // a path longer than |V| edges means the predecessors are looping on a negative cycle
+            if edges.len() >= self.prevs.len() {
+                return None;
+            }
+            let edge = self.prevs[src][node]?;
+            edges.push(edge);
+            node = edge.source();
+        }
+        edges.reverse();
+        Some(Path::from_edges(src, edges))
+    }
+}
+
+impl<E> fmt::Display for Path<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nodes: Vec<String> = self.nodes.iter().map(|node| node.to_string()).collect();
+        write!(f, "{}", nodes.join("->"))
+    }
+}
+
+impl<T> fmt::Display for ShortestPathTree<T> where T: Edge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "source : {}", self.source)?;
+        writeln!(f, " elem | prev | distance |")?;
+        writeln!(f, "------+------+----------+")?;
+        for v in 0..self.prevs.len() {
+            writeln!(f, "  {}  |  {}  |    {}    |", v, format_prev(self.prevs[v].as_ref()), self.dists[v])?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> fmt::Display for BFSTree<T> where T: Edge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "source : {}", self.source)?;
+        writeln!(f, " elem | prev | distance |")?;
+        writeln!(f, "------+------+----------+")?;
+        for v in 0..self.prevs.len() {
+            writeln!(f, "  {}  |  {}  |    {}    |", v, format_prev(self.prevs[v].as_ref()), format_distance(self.distance(v)))?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> fmt::Display for DFSTree<T> where T: Edge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, " elem | prev | discover | finish |")?;
+        writeln!(f, "------+------+----------+--------+")?;
+        for v in 0..self.prevs.len() {
+            writeln!(f, "  {}  |  {}  |    {}    |   {}   |", v, format_prev(self.prevs[v].as_ref()), self.discover[v], self.finish[v])?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> fmt::Display for AllShortestPathMatrix<'_, T> where T: Edge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for src in 0..self.dists.len() {
+            let row: Vec<String> = (0..self.dists.len())
+                .map(|dst| self.dists[src][dst].to_string())
+                .collect();
+            writeln!(f, "{}", row.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Printable for ShortestPathTree<T> where T : Edge {
+    fn print(&self, src : usize, dst : usize) {
+        print!("{} to {} | weight : {} | path : ", src, dst, self.dists[dst]);
+        print_path(self.path_to(dst));
+        println!();
+    }
+}
+
+impl<T> Printable for BFSTree<T>  where T : Edge {
+    fn print(&self, src: usize, dst: usize) {
+        print!("{} to {} | weight : {} | path : ", src, dst, format_distance(self.distance(dst)));
+        print_path(self.path_to(dst));
+        println!();
+    }
+}
+
+impl<T> Printable for DFSTree<T>  where T: Edge {
+    fn print(&self, _: usize, _: usize) {
+        print!("{}", self);
+    }
+}
+
+impl<T> Printable for AllShortestPathMatrix<'_, T>  where T : Edge {
+    fn print(&self, src: usize, dst: usize) {
+        print!("{} to {} | weight : {} | path : ", src, dst , self.dists[src][dst]);
+        print_path(self.path_between(src, dst));
+        println!();
+    }
+}
+
+/// Returns the path from the root of the tree to `dst`, following the predecessors backward.
+fn tree_path<'a, T, P>(pred: P, dst: usize) -> Path<&'a T> where T: Edge, P: Fn(usize) -> Option<&'a T> {
+    let mut edges: Vec<&T> = Vec::new();
+    let mut node = dst;
+    while let Some(edge) = pred(node) {
+        edges.push(edge);
+        node = edge.source();
+    }
+    edges.reverse();
+    Path::from_edges(node, edges)
+}
+
+fn print_path<E>(path: Option<Path<E>>) {
+    match path {
+        Some(path) => print!("{}", path),
+        None => print!("-1"),
+    }
+}
+
+fn format_prev<T>(prev: Option<&T>) -> String where T: Edge {
+    match prev {
+        Some(edge) => edge.source().to_string(),
+        None => String::from("none"),
+    }
+}
+
+fn format_distance<D>(distance: Option<D>) -> String where D: fmt::Display {
+    match distance {
+        Some(distance) => distance.to_string(),
+        None => String::from("inf"),
+    }
+}
@@ -0,0 +1,122 @@
+use std::cmp::Reverse;
+use crate::graphs::{AdjacencyGraph, Edge, Graph, NormalEdge, Weight};
+use crate::graphs::GraphType::GraphDirected;
+
+/// Represents the strongly connected components of a graph: the component id of each node
+/// and the number of components
+#[derive(Debug)]
+pub struct StrongComponents {
+    component: Vec<usize>,
+    count: usize,
+}
+
+/// Represents the condensation of a graph: the directed acyclic graph of its strongly
+/// connected components and the component id of each node of the original graph
+pub struct Condensation<W> where W: Weight {
+    dag: Graph<NormalEdge<W>>,
+    component: Vec<usize>,
+}
+
+#[allow(dead_code)]
+impl StrongComponents {
+    /// Returns the component id of `v`
+    pub fn component(&self, v: usize) -> usize {
+        self.component[v]
+    }
+
+    /// Returns the component id of each node
+    pub fn components(&self) -> &[usize] {
+        &self.component
+    }
+
+    /// Returns the number of components
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+#[allow(dead_code)]
+impl<W> Condensation<W> where W: Weight {
+    /// Returns the graph with a node for each component
+    pub fn dag(&self) -> &Graph<NormalEdge<W>> {
+        &self.dag
+    }
+
+    /// Returns the component id of `v`, that is its node in the condensation graph
+    pub fn component(&self, v: usize) -> usize {
+        self.component[v]
+    }
+
+    /// Returns the component id of each node of the original graph
+    pub fn components(&self) -> &[usize] {
+        &self.component
+    }
+}
+
+impl<T> Graph<T> where T: Edge {
+
+    /// Returns the strongly connected components of the graph with the Kosaraju Algorithm
+    /// ( O(|E|+|V|) with |V| = number of nodes, |E| = number of edges).
+    ///
+    /// The ids follow a topological order of the condensation graph: an edge between two
+    /// different components always goes from the lower id to the higher one.
+    #[allow(dead_code)]
+    pub fn strongly_connected_components(&self) -> StrongComponents {
+        let dfst = self.dfs();
+        let mut order: Vec<usize> = (0..self.n_nodes).collect();
+        order.sort_by_key(|v| Reverse(dfst.finish(*v)));
+
+        let mut reversed: Vec<Vec<usize>> = vec![Vec::new(); self.n_nodes];
+        for edge in self.edges.iter().flatten() {
+            reversed[edge.destination()].push(edge.source());
+        }
+
+        let mut component: Vec<Option<usize>> = vec![None; self.n_nodes];
+        let mut count = 0;
+        let mut stack: Vec<usize> = Vec::new();
+        for root in order {
+            if component[root].is_some() {
+                continue;
+            }
+            component[root] = Some(count);
+            stack.push(root);
+            while let Some(u) = stack.pop() {
+                for &v in reversed[u].as_slice() {
+                    if component[v].is_none() {
+                        component[v] = Some(count);
+                        stack.push(v);
+                    }
+                }
+            }
+            count += 1;
+        }
+
+        StrongComponents {
+            component: component.into_iter().map(|c| c.expect("Every node is visited")).collect(),
+            count,
+        }
+    }
+
+    /// Returns the condensation of the graph: a directed acyclic graph with a node for each
+    /// strongly connected component and an edge between two components when at least one
+    /// edge of the original graph connects them. The weight of that edge is the lowest among
+    /// the original ones.
+    #[allow(dead_code)]
+    pub fn condensation(&self) -> Condensation<T::Weight> {
+        let StrongComponents { component, count } = self.strongly_connected_components();
+        let mut links: Vec<(usize, usize, T::Weight)> = self.edges.iter()
+            .flatten()
+            .map(|edge| (component[edge.source()], component[edge.destination()], edge.weight()))
+            .filter(|(src, dst, _)| src != dst)
+            .collect();
+        links.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1))
+            .then(a.2.partial_cmp(&b.2).expect("Edge weights can't be NaN")));
+        links.dedup_by(|next, first| next.0 == first.0 && next.1 == first.1);
+
+        let mut dag: Graph<NormalEdge<T::Weight>> = Graph::new(count, GraphDirected);
+        for (src, dst, weight) in links {
+            dag.create_edge(src, dst, weight);
+        }
+        Condensation { dag, component }
+    }
+}
@@ -0,0 +1,73 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use crate::graphs::{AdjacencyGraph, CycleError, Edge, Graph, TopologicalOrderError};
+use crate::graphs::GraphType::GraphUndirected;
+
+impl<T> Graph<T> where T: Edge {
+
+    /// Returns a topological order of the nodes, computed by decreasing DFS finish time
+    /// ( O(|E|+|V|) with |V| = number of nodes, |E| = number of edges).
+    ///
+    /// If the graph is cyclic, the error holds a cycle closed by one of the back edges of the DFS.
+    /// Undirected graphs are rejected.
+    #[allow(dead_code)]
+    pub fn topological_order(&self) -> Result<Vec<usize>, TopologicalOrderError<T>> {
+        if self.g_type == GraphUndirected {
+            return Err(TopologicalOrderError::UndirectedGraph);
+        }
+        let dfst = self.dfs();
+
+        for edge in self.edges.iter().flatten() {
+            let (u, v) = (edge.source(), edge.destination());
+            // v is an ancestor of u in the DFS forest: the edge closes a cycle
+            if dfst.discover(v) <= dfst.discover(u) && dfst.finish(u) <= dfst.finish(v) {
+                let mut cycle: Vec<T> = vec![edge.clone()];
+                let mut node = u;
+                while node != v {
+                    let prev = dfst.predecessor(node).expect("Ancestors are reached through tree edges");
+                    cycle.push(prev.clone());
+                    node = prev.source();
+                }
+                cycle.reverse();
+                return Err(TopologicalOrderError::Cycle(CycleError { cycle }));
+            }
+        }
+
+        let mut order: Vec<usize> = (0..self.n_nodes).collect();
+        order.sort_by_key(|v| Reverse(dfst.finish(*v)));
+        Ok(order)
+    }
+
+    /// Returns the lexicographically smallest topological order of the nodes with the Kahn
+    /// Algorithm ( O(|E|+|V|log(|V|)) with |V| = number of nodes, |E| = number of edges).
+    ///
+    /// If the graph is cyclic, the error holds one of its cycles. Undirected graphs are rejected.
+    #[allow(dead_code)]
+    pub fn lexicographic_topological_order(&self) -> Result<Vec<usize>, TopologicalOrderError<T>> {
+        if self.g_type == GraphUndirected {
+            return Err(TopologicalOrderError::UndirectedGraph);
+        }
+        let mut in_deg = self.in_deg.clone();
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..self.n_nodes)
+            .filter(|i| in_deg[*i] == 0)
+            .map(Reverse)
+            .collect();
+        let mut order: Vec<usize> = Vec::with_capacity(self.n_nodes);
+
+        while let Some(Reverse(u)) = ready.pop() {
+            order.push(u);
+            for edge in self.edges[u].as_slice() {
+                let dst = edge.destination();
+                in_deg[dst] -= 1;
+                if in_deg[dst] == 0 {
+                    ready.push(Reverse(dst));
+                }
+            }
+        }
+
+        if order.len() < self.n_nodes {
+            return self.topological_order();
+        }
+        Ok(order)
+    }
+}
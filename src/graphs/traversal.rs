@@ -0,0 +1,293 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use crate::graphs::{BFSTree, CycleError, DFSTree, Distance, Edge, NegativeEdgeError, predecessors_cycle, SearchTree, ShortestPathTree, Weight};
+use crate::graphs::Color::{BLACK, GREY, WHITE};
+use crate::graphs::Color;
+use crate::MinHeap;
+
+/// Graphs that can list the out-edges of each node, whether they are stored (`Graph`,
+/// `CsrGraph`) or computed on demand (`RobotGrid`).
+///
+/// The traversal and single source shortest path algorithms only need the number of nodes
+/// and the out-edges of a node, so they are written once here and run on all of them.
+/// Since the edges may not be stored anywhere, their results keep their own copy of the
+/// edges they report.
+pub trait AdjacencyGraph<T> where T: Edge {
+    /// Returns the number of nodes, numbered from 0
+    fn n_nodes(&self) -> usize;
+
+    /// Returns the edges starting from `v`, always in the same order
+    fn out_edges(&self, v: usize) -> impl Iterator<Item = T> + '_;
+
+    /// Returns the shortest path tree with the BFS Algorithm
+    /// ( O(|E|+|V|) with |V| = number of nodes, |E| = number of edges) of the given graph
+    /// from the give source.
+    ///
+    /// # Arguments
+    /// * `source` - source node of the shortest path tree
+    ///
+    fn bfs(&self, source: usize) -> BFSTree<T> {
+
+        let mut colors: Vec<Color> = vec![WHITE; self.n_nodes()];
+        let mut distances: Vec<i32> = vec![-1; self.n_nodes()];
+        let mut prev_edge: Vec<Option<T>> = vec![None; self.n_nodes()];
+        let mut queue: VecDeque<usize> = VecDeque::new();
+
+        colors[source] = GREY;
+        distances[source] = 0;
+
+        queue.push_back(source);
+
+        while let Some(src) = queue.pop_front() {
+            for edge in self.out_edges(src) {
+                let dst = edge.destination();
+                if colors[dst] == WHITE {
+                    colors[dst] = GREY;
+                    distances[dst] = distances[src] + 1;
+                    prev_edge[dst] = Some(edge);
+                    queue.push_back(dst);
+                }
+            }
+            colors[src] = BLACK;
+        }
+
+        BFSTree::new(source, prev_edge, distances)
+    }
+
+    /// Returns a spanning tree of the given graph with the Depth First Search
+    /// ( O(|E|+|V|) with |V| = number of nodes, |E| = number of edges), along with the
+    /// discover and finish time of each node.
+    ///
+    fn dfs(&self) -> DFSTree<T> {
+        let mut discover: Vec<usize> = vec![0;self.n_nodes()];
+        let mut finish: Vec<usize> = vec![0;self.n_nodes()];
+        let mut color: Vec<Color> = vec![WHITE;self.n_nodes()];
+        let mut prev: Vec<Option<T>> = vec![None;self.n_nodes()];
+        let mut time = 0;
+
+        for i in 0..self.n_nodes() {
+            if color[i] == WHITE {
+                dfs_visit(self, i, &mut discover, &mut finish, &mut color, &mut prev, &mut time);
+            }
+        }
+
+        DFSTree::new(prev, discover, finish)
+    }
+
+    /// Returns the shortest path tree with the Dijkstra Algorithm (O(|E|+|V|log(|V|) with |V| = number of nodes, |E| = number of edges) of the given graph
+    /// from the give source.
+    ///
+    /// # Arguments
+    /// * `source` - source node of the shortest path tree
+    fn dijkstra(&self, source: usize) -> Result<ShortestPathTree<T>, NegativeEdgeError> {
+        dijkstra_by(self, source, |edge| edge.weight())
+    }
+
+    /// Returns the shortest path from `source` to `target` with the A* Algorithm
+    /// (O(|E|log(|E|)) in the worst case, usually far less) of the given graph.
+    ///
+    /// The search stops as soon as the target is extracted from the heap and only keeps the
+    /// nodes it discovers, so its cost depends on the explored part of the graph and not on
+    /// its size: only the distances of the nodes on the path are guaranteed to be the final ones.
+    ///
+    /// The heuristic must be consistent (never overestimate the cost of an edge plus the
+    /// estimate of its destination), otherwise the returned path may not be the shortest.
+    ///
+    /// # Arguments
+    /// * `source` - source node of the path
+    /// * `target` - destination node of the path
+    /// * `heuristic` - estimated distance from a node to the target
+    fn a_star<H>(&self, source: usize, target: usize, heuristic: H) -> Result<SearchTree<T>, NegativeEdgeError>
+        where H: Fn(usize) -> T::Weight {
+        let zero = T::Weight::zero();
+        let mut distances: HashMap<usize, Distance<T::Weight>> = HashMap::new();
+        let mut prev_edge: HashMap<usize, T> = HashMap::new();
+        let mut added: HashSet<usize> = HashSet::new();
+        let mut heap: BinaryHeap<Frontier<T::Weight>> = BinaryHeap::new();
+
+        distances.insert(source, Distance::Finite(zero));
+        heap.push(Frontier { estimate: Distance::Finite(heuristic(source)), node: source });
+
+        // a node is pushed again each time its distance improves, the older entries are skipped
+        while let Some(Frontier { node: u, .. }) = heap.pop() {
+            if !added.insert(u) {
+                continue;
+            }
+            if u == target {
+                break;
+            }
+            let distance = distances[&u];
+            for edge in self.out_edges(u) {
+                let weight = edge.weight();
+                let dst = edge.destination();
+                if weight < zero {
+                    return Err(NegativeEdgeError)
+                }
+                let candidate = distance.add(weight);
+                if !added.contains(&dst) && !matches!(distances.get(&dst), Some(known) if *known <= candidate) {
+                    distances.insert(dst, candidate);
+                    heap.push(Frontier { estimate: candidate.add(heuristic(dst)), node: dst });
+                    prev_edge.insert(dst, edge);
+                }
+            }
+        }
+
+        Ok(SearchTree::new(source, prev_edge, distances))
+    }
+
+    /// Returns the shortest path tree with the Bellman-Ford Algorithm (O(|E||V|) with |V| = number of nodes, |E| = number of edges) of the given graph
+    /// from the give source.
+    ///
+    /// If a negative cycle is reachable from the source, the error holds its edges, even when
+    /// the distances of its nodes went out of the range of the weight type.
+    ///
+    /// # Arguments
+    /// * `source` - source node of the shortest path tree
+    fn bellman_ford(&self, source: usize) -> Result<ShortestPathTree<T>, CycleError<T>> {
+        let mut distances: Vec<Distance<T::Weight>> = vec![Distance::Infinite; self.n_nodes()];
+        let mut prev_edge: Vec<Option<T>> = vec![None; self.n_nodes()];
+
+        distances[source] = Distance::Finite(T::Weight::zero());
+
+        for _ in 0..self.n_nodes()-1 {
+            for i in 0..self.n_nodes() {
+                for edge in self.out_edges(i) {
+                    let candidate = distances[edge.source()].add(edge.weight());
+                    let dst = edge.destination();
+                    if candidate < distances[dst] {
+                        distances[dst] = candidate;
+                        prev_edge[dst] = Some(edge);
+                    }
+                }
+            }
+        }
+
+        for i in 0..self.n_nodes() {
+            for edge in self.out_edges(i) {
+                let dst = edge.destination();
+                if distances[edge.source()].add(edge.weight()) < distances[dst] {
+                    prev_edge[dst] = Some(edge);
+                    if let Some(cycle) = negative_predecessors_cycle(&prev_edge, dst) {
+                        return Err(CycleError { cycle });
+                    }
+                }
+            }
+        }
+        // the distances that underflowed can't decrease any more, so the negative cycles
+        // through them only show in the predecessors
+        for (v, distance) in distances.iter().enumerate() {
+            if *distance == Distance::Underflow {
+                if let Some(cycle) = negative_predecessors_cycle(&prev_edge, v) {
+                    return Err(CycleError { cycle });
+                }
+            }
+        }
+        Ok(ShortestPathTree::new(source, prev_edge, distances))
+    }
+}
+
+/// Returns the cycle found following the predecessors backward from `node`, if there is one
+/// and its total weight is negative.
+fn negative_predecessors_cycle<T>(pred: &[Option<T>], node: usize) -> Option<Vec<T>> where T: Edge {
+    let cycle = predecessors_cycle(pred, node)?;
+    let weight = Distance::sum(cycle.iter().map(|edge| edge.weight()));
+    if weight < Distance::Finite(T::Weight::zero()) { Some(cycle) } else { None }
+}
+
+/// Visits the nodes reachable from `root` that are still white, for `AdjacencyGraph::dfs`.
+///
+/// The visit keeps its own stack of (node, out-edges still to follow) instead of recursing,
+/// so long paths can't overflow the call stack.
+fn dfs_visit<G, T>(graph: &G, root: usize, discover: &mut [usize], finish: &mut [usize]
+                   , color: &mut [Color], prev: &mut [Option<T>], time: &mut usize)
+    where G: AdjacencyGraph<T> + ?Sized, T: Edge {
+    *time += 1;
+    discover[root] = *time;
+    color[root] = GREY;
+    let mut stack = vec![(root, graph.out_edges(root))];
+
+    while let Some((src, out_edges)) = stack.last_mut() {
+        let src = *src;
+        match out_edges.next() {
+            Some(edge) => {
+                let dst = edge.destination();
+                if color[dst] == WHITE {
+                    prev[dst] = Some(edge);
+                    *time += 1;
+                    discover[dst] = *time;
+                    color[dst] = GREY;
+                    stack.push((dst, graph.out_edges(dst)));
+                }
+            }
+            None => {
+                stack.pop();
+                color[src] = BLACK;
+                *time += 1;
+                finish[src] = *time;
+            }
+        }
+    }
+}
+
+/// Dijkstra Algorithm where the cost of each edge is given by `weight` instead of its own weight.
+pub(crate) fn dijkstra_by<G, T, W>(graph: &G, source: usize, weight: W) -> Result<ShortestPathTree<T>, NegativeEdgeError>
+    where G: AdjacencyGraph<T> + ?Sized, T: Edge, W: Fn(&T) -> T::Weight {
+    let zero = T::Weight::zero();
+    let mut distances: Vec<Distance<T::Weight>> = vec![Distance::Infinite; graph.n_nodes()];
+    let mut heap = MinHeap::new();
+    let mut added = vec![false; graph.n_nodes()];
+    let mut prev_edge: Vec<Option<T>> = vec![None; graph.n_nodes()];
+
+    distances[source] = Distance::Finite(zero);
+
+    for (i, distance) in distances.iter().enumerate() {
+        heap.insert(i, *distance)
+    }
+
+    while !heap.is_empty() {
+        let u = heap.delete_min();
+        added[u] = true;
+        for edge in graph.out_edges(u) {
+            let weight = weight(&edge);
+            let dst = edge.destination();
+            if weight < zero {
+                return Err(NegativeEdgeError)
+            }
+            let candidate = distances[u].add(weight);
+            if !added[dst] && candidate < distances[dst] {
+                distances[dst] = candidate;
+                heap.change_prio(dst, candidate);
+                prev_edge[dst] = Some(edge);
+            }
+        }
+    }
+
+    Ok(ShortestPathTree::new(source, prev_edge, distances))
+}
+
+/// Node waiting in the A* heap with the estimated weight of the paths through it, ordered
+/// so that `BinaryHeap` extracts the lowest estimate first
+struct Frontier<W> where W: Weight {
+    estimate: Distance<W>,
+    node: usize,
+}
+
+impl<W> PartialEq for Frontier<W> where W: Weight {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W> Eq for Frontier<W> where W: Weight {}
+
+impl<W> PartialOrd for Frontier<W> where W: Weight {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W> Ord for Frontier<W> where W: Weight {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.partial_cmp(&self.estimate).unwrap_or(Ordering::Equal)
+    }
+}
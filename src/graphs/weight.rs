@@ -0,0 +1,172 @@
+use std::fmt;
+use std::ops::{Add, Sub};
+
+/// Types that can weigh the edges of a graph: integers of any size, `f32` and `f64`, or any
+/// other numeric type (e.g. exact rationals) implementing it.
+///
+/// The algorithms add up weights through `Distance`, built on `checked_add` and `checked_sub`,
+/// so a sum that can't be represented is reported as out of range instead of wrapping around.
+pub trait Weight: Copy + PartialOrd + fmt::Debug + fmt::Display + Add<Output = Self> + Sub<Output = Self> {
+    fn zero() -> Self;
+
+    /// Returns the unit weight, given to the edges of the formats that don't weigh them
+    fn one() -> Self;
+
+    /// Returns the sum of the two weights, None if it can't be represented
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Returns the difference of the two weights, None if it can't be represented
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! integer_weight {
+    ($($t:ty),*) => {
+        $(impl Weight for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+        })*
+    };
+}
+
+macro_rules! float_weight {
+    ($($t:ty),*) => {
+        $(impl Weight for $t {
+            fn zero() -> Self {
+                0.0
+            }
+
+            fn one() -> Self {
+                1.0
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                let sum = self + other;
+                if sum.is_finite() { Some(sum) } else { None }
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                let difference = self - other;
+                if difference.is_finite() { Some(difference) } else { None }
+            }
+        })*
+    };
+}
+
+integer_weight!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+float_weight!(f32, f64);
+
+/// Distance between two nodes: `Infinite` when there is no path, greater than any other one.
+///
+/// A path whose weight can't be represented by the weight type is `Underflow` when it's below
+/// the smallest value, and `Overflow` when it's above the largest one. Such a distance stays
+/// out of range even if the following edges would bring it back: it's exact when all the
+/// weights of the path have the same sign, as in Dijkstra.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum Distance<W> where W: Weight {
+    Underflow,
+    Finite(W),
+    Overflow,
+    Infinite,
+}
+
+#[allow(dead_code)]
+impl<W> Distance<W> where W: Weight {
+    /// Returns the distance extended by an edge of weight `weight`, out of range in the
+    /// direction of the weight if the sum can't be represented
+    pub fn add(self, weight: W) -> Self {
+        match self {
+            Distance::Finite(d) => match d.checked_add(weight) {
+                Some(sum) => Distance::Finite(sum),
+                None if weight < W::zero() => Distance::Underflow,
+                None => Distance::Overflow,
+            },
+            other => other,
+        }
+    }
+
+    /// Returns the distance shortened by an edge of weight `weight`, out of range in the
+    /// opposite direction of the weight if the difference can't be represented
+    pub fn sub(self, weight: W) -> Self {
+        match self {
+            Distance::Finite(d) => match d.checked_sub(weight) {
+                Some(difference) => Distance::Finite(difference),
+                None if weight < W::zero() => Distance::Overflow,
+                None => Distance::Underflow,
+            },
+            other => other,
+        }
+    }
+
+    /// Returns the sum of the two distances: infinite if one of them is, otherwise out of range
+    /// if one of them is (the first one if both are) or if the sum can't be represented
+    pub fn join(self, other: Self) -> Self {
+        match (self, other) {
+            (Distance::Infinite, _) | (_, Distance::Infinite) => Distance::Infinite,
+            (distance, Distance::Finite(d)) => distance.add(d),
+            (Distance::Finite(_), out_of_range) => out_of_range,
+            (out_of_range, _) => out_of_range,
+        }
+    }
+
+    /// Returns the sum of the `weights`, out of range if it can't be represented.
+    ///
+    /// The negative and the positive weights are added alternately, so that the partial sums
+    /// stay between the smallest and the largest weight: the result is out of range only if
+    /// the total is.
+    pub fn sum<I>(weights: I) -> Self where I: IntoIterator<Item = W> {
+        let zero = W::zero();
+        let (mut negative, mut positive): (Vec<W>, Vec<W>) = weights.into_iter().partition(|w| *w < zero);
+        let mut sum = Distance::Finite(zero);
+        loop {
+            let next = match sum {
+                Distance::Finite(s) if s >= zero => negative.pop().or_else(|| positive.pop()),
+                _ => positive.pop().or_else(|| negative.pop()),
+            };
+            match next {
+                Some(weight) => sum = sum.add(weight),
+                None => return sum,
+            }
+        }
+    }
+
+    /// Returns the finite value, None for the infinite and the out of range distances
+    pub fn finite(self) -> Option<W> {
+        match self {
+            Distance::Finite(d) => Some(d),
+            _ => None,
+        }
+    }
+
+    pub fn is_finite(&self) -> bool {
+        matches!(self, Distance::Finite(_))
+    }
+
+    /// Returns true if there is a path, even if its weight is out of range
+    pub fn is_reachable(&self) -> bool {
+        !matches!(self, Distance::Infinite)
+    }
+}
+
+impl<W> fmt::Display for Distance<W> where W: Weight {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Distance::Underflow => write!(f, "underflow"),
+            Distance::Finite(d) => write!(f, "{}", d),
+            Distance::Overflow => write!(f, "overflow"),
+            Distance::Infinite => write!(f, "inf"),
+        }
+    }
+}
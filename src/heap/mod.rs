@@ -1,10 +1,12 @@
-pub struct HeapElem {
+pub struct HeapElem<P = f32> {
     key: usize,
-    pub prio: f32,
+    pub prio: P,
 }
 
-pub struct MinHeap {
-    pub heap: Vec<HeapElem>,
+/// Indexed min heap: the keys are the integers 0..n, inserted in order, and each one has a
+/// priority of any ordered type
+pub struct MinHeap<P = f32> {
+    pub heap: Vec<HeapElem<P>>,
     positions: Vec<i32>,
 }
 
@@ -21,15 +23,15 @@ fn r_child(i: usize) -> usize {
 }
 
 #[allow(dead_code)]
-impl MinHeap {
-    pub fn new() -> MinHeap {
+impl<P> MinHeap<P> where P: PartialOrd + Copy {
+    pub fn new() -> MinHeap<P> {
         return MinHeap {
             heap: Vec::new(),
             positions: Vec::new(),
         };
     }
 
-    pub fn insert(&mut self, key: usize, prio: f32) {
+    pub fn insert(&mut self, key: usize, prio: P) {
         self.heap.push(HeapElem { key, prio });
         self.positions.push((self.count() - 1) as i32);
         self.move_up(self.count() - 1);
@@ -40,12 +42,13 @@ impl MinHeap {
         self.heap.swap_remove(0);
         self.positions[result] = -1;
         if !self.is_empty() {
+            self.positions[self.heap[0].key] = 0;
             self.move_down(0);
         }
         result
     }
 
-    pub fn change_prio(&mut self, key: usize, new_prio: f32) {
+    pub fn change_prio(&mut self, key: usize, new_prio: P) {
         let j = self.positions[key] as usize;
         let old_prio = self.heap[j].prio;
         self.heap[j].prio = new_prio;
@@ -3,7 +3,7 @@ use std::io::{BufRead, BufReader, Error};
 use sscanf::{scanf};
 use crate::Edge;
 
-use crate::graphs::{Graph};
+use crate::graphs::{AdjacencyGraph, BFSTree, Graph, Path, PathTree, SearchTree, ShortestPathTree};
 use crate::graphs::GraphType::GraphUndirected;
 
 #[allow(dead_code)]
@@ -16,6 +16,8 @@ pub struct RobotEdge{
 }
 
 impl Edge for RobotEdge{
+    type Weight = f32;
+
     fn new(src: usize, dst: usize, weight: f32) -> Self {
         RobotEdge{src, dst, weight, direction : 'N'}
     }
@@ -39,6 +41,10 @@ impl Edge for RobotEdge{
     fn weight(&self) -> f32 {
         self.weight
     }
+
+    fn set_weight(&mut self, weight: f32) {
+        self.weight = weight
+    }
 }
 
 #[allow(dead_code)]
@@ -52,63 +58,93 @@ impl RobotEdge {
     }
 }
 
+/// Heuristics that can guide the A* search on a robot grid.
 #[allow(dead_code)]
-pub fn robot_print_bfs(
-    src: usize,
-    dst: usize,
-    dist: Vec<i32>,
-    prev_edge: &Vec<Option<&RobotEdge>>,
-) {
-    if dist[dst] > 0 {
-        println!("{}", dist[dst]);
-        robot_print_path(src, dst, prev_edge);
-        println!();
-    } else {
-        println!("{}", -1);
+pub enum GridHeuristic {
+    /// Sum of the horizontal and vertical offsets, exact on a grid without obstacles
+    Manhattan,
+    /// Straight line distance, looser than the Manhattan one
+    Euclidean,
+}
+
+#[allow(dead_code)]
+impl GridHeuristic {
+    /// Returns the estimated distance between two cells given as (row, column)
+    pub fn estimate(&self, from: (usize, usize), to: (usize, usize)) -> f32 {
+        let rows = (from.0 as f32 - to.0 as f32).abs();
+        let cols = (from.1 as f32 - to.1 as f32).abs();
+        match self {
+            GridHeuristic::Manhattan => rows + cols,
+            GridHeuristic::Euclidean => (rows * rows + cols * cols).sqrt(),
+        }
     }
 }
 
+/// Returns the shortest path between two positions of a robot grid with the A* Algorithm.
+///
+/// Positions are numbered row by row, so the heuristic finds their row and column from the
+/// width of the grid.
+///
+/// # Arguments
+/// * `grid` - robot grid built with `robot_grid_from_file`
+/// * `src` - starting position
+/// * `dst` - target position
+/// * `heuristic` - distance estimate used to guide the search
 #[allow(dead_code)]
-pub fn robot_print_dijkstra(
-    src: usize,
-    dst: usize,
-    prev_edge: &Vec<Option<&RobotEdge>>,
-    dist: Vec<usize>,
-) {
-    if dist[dst] != (usize::MAX - (1000 * 1000)) {
-        println!("{}", dist[dst]);
-        robot_print_path(src, dst, prev_edge);
-        println!();
-    } else {
-        println!("{}", -1);
+pub fn robot_a_star(grid: &RobotGrid, src: usize, dst: usize, heuristic: GridHeuristic) -> SearchTree<RobotEdge> {
+    let width = grid.cols;
+    grid.a_star(src, dst, |node| heuristic.estimate((node / width, node % width), (dst / width, dst % width)))
+        .expect("Robot edges can't be negative")
+}
+
+#[allow(dead_code)]
+pub fn robot_print_bfs(dst: usize, bfst: &BFSTree<RobotEdge>) {
+    match bfst.distance(dst) {
+        Some(dist) if dist > 0 => {
+            println!("{}", dist);
+            robot_print_path(bfst.path_to(dst));
+            println!();
+        }
+        _ => println!("{}", -1),
     }
 }
 
-fn robot_print_path(
-    src: usize,
-    dst: usize,
-    prev_edge: &Vec<Option<&RobotEdge>>,
-) {
-    if src != dst {
-        match prev_edge[dst] {
-            Some(edge) => {
-                robot_print_path(src, edge.source(), prev_edge);
-                match prev_edge[dst] {
-                    Some(edge) => {
-                        print!("{}", edge.direction());
-                    },
-                    None =>()
-                }
+#[allow(dead_code)]
+pub fn robot_print_dijkstra(dst: usize, spt: &ShortestPathTree<RobotEdge>) {
+    match spt.distance(dst) {
+        Some(dist) => {
+            println!("{}", dist);
+            robot_print_path(spt.path_to(dst));
+            println!();
+        }
+        None => println!("{}", -1),
+    }
+}
 
-            }
-            None => println!("-1"),
+fn robot_print_path(path: Option<Path<&RobotEdge>>) {
+    if let Some(path) = path {
+        for edge in path.edges() {
+            print!("{}", edge.direction());
         }
     }
 }
 
 #[allow(dead_code)]
 pub fn robot_graph_from_file(path: String, cell_size: (usize, usize)) -> Result<Graph<RobotEdge>, Error> {
-    let (grid, rows, cols) = read_grid_from_file(path)?;
+    robot_graph_from_reader(BufReader::new(fs::File::open(path)?), cell_size)
+}
+
+#[allow(dead_code)]
+pub fn robot_graph_from_str(text: &str, cell_size: (usize, usize)) -> Result<Graph<RobotEdge>, Error> {
+    robot_graph_from_reader(text.as_bytes(), cell_size)
+}
+
+/// Returns the graph of the positions a robot of size `cell_size` can take in the grid read
+/// from `reader`: the first line holds the number of rows and columns, each following line is
+/// a row of the grid, where '*' marks an obstacle.
+#[allow(dead_code)]
+pub fn robot_graph_from_reader<R>(reader: R, cell_size: (usize, usize)) -> Result<Graph<RobotEdge>, Error> where R: BufRead {
+    let (grid, rows, cols) = read_grid(reader)?;
     let mut graph: Graph<RobotEdge> = Graph::new(
         (rows - (cell_size.0 - 1)) * (cols - (cell_size.1 - 1)),
         GraphUndirected,
@@ -156,13 +192,73 @@ pub fn robot_graph_from_file(path: String, cell_size: (usize, usize)) -> Result<
     Ok(graph)
 }
 
-fn read_grid_from_file(path: String) -> Result<(Vec<Vec<char>>, usize, usize), Error> {
-    let buff_reader = BufReader::new(fs::File::open(path)?);
+/// Grid of the positions a robot can take, whose moves are computed from the cells instead
+/// of being stored in a `Graph`: nothing but the grid itself is kept in memory, and the moves
+/// from a position are found each time a search expands it.
+///
+/// Positions and moves are numbered and ordered as in `robot_graph_from_reader`, so the
+/// searches give the same results on both.
+pub struct RobotGrid {
+    grid: Vec<Vec<char>>,
+    cell_size: (usize, usize),
+    /// Number of rows and columns of positions
+    rows: usize,
+    cols: usize,
+}
+
+impl RobotGrid {
+    fn new(grid: Vec<Vec<char>>, rows: usize, cols: usize, cell_size: (usize, usize)) -> Self {
+        RobotGrid { grid, cell_size, rows: rows - (cell_size.0 - 1), cols: cols - (cell_size.1 - 1) }
+    }
+}
+
+impl AdjacencyGraph<RobotEdge> for RobotGrid {
+    fn n_nodes(&self) -> usize {
+        self.rows * self.cols
+    }
+
+    /// Returns the moves from position `v` towards the free positions around it,
+    /// in the north, west, east, south order
+    fn out_edges(&self, v: usize) -> impl Iterator<Item = RobotEdge> + '_ {
+        let (i, j) = (v / self.cols, v % self.cols);
+        let free = is_a_node(&self.grid, i, j, '*', self.cell_size);
+        let neighbours = [
+            (i > 0).then(|| (i - 1, j, 'N')),
+            (j > 0).then(|| (i, j - 1, 'W')),
+            (j + 1 < self.cols).then(|| (i, j + 1, 'E')),
+            (i + 1 < self.rows).then(|| (i + 1, j, 'S')),
+        ];
+        IntoIterator::into_iter(neighbours)
+            .flatten()
+            .filter(move |(x, y, _)| free && is_a_node(&self.grid, *x, *y, '*', self.cell_size))
+            .map(move |(x, y, direction)| RobotEdge { src: v, dst: x * self.cols + y, weight: 1.0, direction })
+    }
+}
+
+#[allow(dead_code)]
+pub fn robot_grid_from_file(path: String, cell_size: (usize, usize)) -> Result<RobotGrid, Error> {
+    robot_grid_from_reader(BufReader::new(fs::File::open(path)?), cell_size)
+}
+
+#[allow(dead_code)]
+pub fn robot_grid_from_str(text: &str, cell_size: (usize, usize)) -> Result<RobotGrid, Error> {
+    robot_grid_from_reader(text.as_bytes(), cell_size)
+}
+
+/// Returns the implicit grid of the positions a robot of size `cell_size` can take in the grid
+/// read from `reader`, in the format of `robot_graph_from_reader`.
+#[allow(dead_code)]
+pub fn robot_grid_from_reader<R>(reader: R, cell_size: (usize, usize)) -> Result<RobotGrid, Error> where R: BufRead {
+    let (grid, rows, cols) = read_grid(reader)?;
+    Ok(RobotGrid::new(grid, rows, cols, cell_size))
+}
+
+fn read_grid<R>(reader: R) -> Result<(Vec<Vec<char>>, usize, usize), Error> where R: BufRead {
     let mut i: i32 = -1;
     let mut x: usize = 0;
     let mut y: usize = 0;
     let mut grid: Vec<Vec<char>> = Vec::new();
-    for line in buff_reader.lines() {
+    for line in reader.lines() {
         if i == -1 {
             let size = read_first_line(line)?;
             x = size.0;
@@ -183,7 +279,10 @@ fn read_grid_from_file(path: String) -> Result<(Vec<Vec<char>>, usize, usize), E
 
 fn read_first_line(line: Result<String, Error>) -> Result<(usize, usize), Error> {
     let parsed: (usize, usize) = match line {
-        Ok(line) => scanf!(line, "{} {}", usize, usize).expect("First line wrongly formatted"),
+        Ok(line) => scanf!(line, "{} {}", usize, usize).map_err(|_| Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "First line wrongly formatted",
+        ))?,
         //This error occurs when there is any first line
         Err(_) => {
             return Err(Error::new(